@@ -0,0 +1,108 @@
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use rand::RngCore;
+use rusqlite::Connection;
+use sha2::{Digest, Sha256};
+use std::env;
+
+use crate::database::api_keys::{find_active_api_key, ApiKey};
+use crate::database::db::TaxRateError;
+use crate::messages::{render_message, Language};
+
+// Partner keys look like "cvk_" followed by 32 random bytes in hex.
+static API_KEY_PREFIX: &str = "cvk_";
+pub static API_KEY_HEADER: &str = "x-api-key";
+
+// Returns a new key and the hash to store for it.
+pub fn generate_api_key() -> (String, String) {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let key = format!("{API_KEY_PREFIX}{}", hex::encode(bytes));
+    let key_hash = hash_api_key(&key);
+    (key, key_hash)
+}
+
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+// The caller of a request, partners identify themselves with the X-API-Key header and
+// everyone else is anonymous. An unknown or revoked key is rejected rather than treated
+// as anonymous, so that partners notice it.
+#[derive(Debug, Clone)]
+pub enum Client {
+    Anonymous,
+    Partner(ApiKey),
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Client {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Some(key) = parts.headers.get(API_KEY_HEADER).cloned() else {
+            return Ok(Client::Anonymous);
+        };
+        let language = Language::from_request_parts(parts, state)
+            .await
+            .unwrap_or(Language::English);
+        let unauthorized = || {
+            (
+                StatusCode::UNAUTHORIZED,
+                render_message(language, "invalid_api_key", "", None),
+            )
+                .into_response()
+        };
+        let key_hash = hash_api_key(key.to_str().map_err(|_| unauthorized())?.trim());
+
+        let api_key = Connection::open("./tax_rates.db")
+            .and_then(|conn| find_active_api_key(&conn, &key_hash))
+            .map_err(|error| {
+                TaxRateError::from(error)
+                    .localized(language)
+                    .into_response()
+            })?;
+        api_key.map(Client::Partner).ok_or_else(unauthorized)
+    }
+}
+
+// Guards the admin routes, the request needs `Authorization: Bearer <ADMIN_TOKEN>`. The
+// admin routes are disabled when ADMIN_TOKEN is unset.
+pub struct AdminToken;
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AdminToken {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let expected = env::var("ADMIN_TOKEN")
+            .ok()
+            .filter(|token| !token.trim().is_empty());
+        let given = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|header| header.to_str().ok())
+            .and_then(|header| header.strip_prefix("Bearer "));
+
+        match (expected, given) {
+            // Comparing the hashes keeps the comparison time independent of the token.
+            (Some(expected), Some(given)) if hash_api_key(&expected) == hash_api_key(given) => {
+                Ok(AdminToken)
+            }
+            _ => {
+                let language = Language::from_request_parts(parts, state)
+                    .await
+                    .unwrap_or(Language::English);
+                Err((
+                    StatusCode::UNAUTHORIZED,
+                    render_message(language, "unauthorized", "", None),
+                )
+                    .into_response())
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::metrics::DB_QUERY_DURATION_SECONDS;
+use rusqlite::{params, Connection, OptionalExtension, Result, Row};
+
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub id: i64,
+    pub name: String,
+    pub prefix: String,
+    pub daily_quota: u32,
+    pub requests_per_minute: u32,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ApiKeyUsage {
+    pub api_key: ApiKey,
+    pub requests_today: u32,
+    pub requests_total: u32,
+}
+
+static API_KEY_COLUMNS: &str =
+    "id, name, prefix, daily_quota, requests_per_minute, created_at, revoked_at";
+
+fn api_key_from_row(row: &Row) -> Result<ApiKey> {
+    Ok(ApiKey {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        prefix: row.get(2)?,
+        daily_quota: row.get(3)?,
+        requests_per_minute: row.get(4)?,
+        created_at: row.get(5)?,
+        revoked_at: row.get(6)?,
+    })
+}
+
+// Function to store a new key, the key itself is only known to the partner.
+pub fn insert_api_key(
+    conn: &Connection,
+    name: &str,
+    key_hash: &str,
+    prefix: &str,
+    daily_quota: u32,
+    requests_per_minute: u32,
+) -> Result<ApiKey> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["insert_api_key"])
+        .start_timer();
+    conn.execute(
+        "INSERT INTO api_keys (name, key_hash, prefix, daily_quota, requests_per_minute, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, datetime('now'))",
+        params![name, key_hash, prefix, daily_quota, requests_per_minute],
+    )?;
+    conn.query_row(
+        &format!("SELECT {API_KEY_COLUMNS} FROM api_keys WHERE id = ?1"),
+        params![conn.last_insert_rowid()],
+        api_key_from_row,
+    )
+}
+
+// Function to query the key matching a hash, revoked keys are not returned.
+pub fn find_active_api_key(conn: &Connection, key_hash: &str) -> Result<Option<ApiKey>> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["find_active_api_key"])
+        .start_timer();
+    conn.query_row(
+        &format!(
+            "SELECT {API_KEY_COLUMNS} FROM api_keys WHERE key_hash = ?1 AND revoked_at IS NULL"
+        ),
+        params![key_hash],
+        api_key_from_row,
+    )
+    .optional()
+}
+
+// Function to query every key with its usage for the given day.
+pub fn list_api_keys(conn: &Connection, day: &str) -> Result<Vec<ApiKeyUsage>> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["list_api_keys"])
+        .start_timer();
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {API_KEY_COLUMNS},
+            (SELECT COALESCE(SUM(requests), 0) FROM api_key_usage
+             WHERE key_id = api_keys.id AND day = ?1),
+            (SELECT COALESCE(SUM(requests), 0) FROM api_key_usage WHERE key_id = api_keys.id)
+         FROM api_keys ORDER BY id"
+    ))?;
+    let api_keys = stmt
+        .query_map(params![day], |row| {
+            Ok(ApiKeyUsage {
+                api_key: api_key_from_row(row)?,
+                requests_today: row.get(7)?,
+                requests_total: row.get(8)?,
+            })
+        })?
+        .collect();
+    api_keys
+}
+
+// Function to revoke a key, returns false when there is no active key with this id.
+pub fn revoke_api_key(conn: &Connection, id: i64) -> Result<bool> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["revoke_api_key"])
+        .start_timer();
+    let updated = conn.execute(
+        "UPDATE api_keys SET revoked_at = datetime('now') WHERE id = ?1 AND revoked_at IS NULL",
+        params![id],
+    )?;
+    Ok(updated == 1)
+}
+
+// Function to count a request against the daily quota of a key, returns false without
+// counting it when the quota is used up.
+pub fn record_api_key_usage(conn: &Connection, api_key: &ApiKey, day: &str) -> Result<bool> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["record_api_key_usage"])
+        .start_timer();
+    conn.execute(
+        "INSERT INTO api_key_usage (key_id, day, requests) VALUES (?1, ?2, 0)
+         ON CONFLICT(key_id, day) DO NOTHING",
+        params![api_key.id, day],
+    )?;
+    let updated = conn.execute(
+        "UPDATE api_key_usage SET requests = requests + 1
+         WHERE key_id = ?1 AND day = ?2 AND requests < ?3",
+        params![api_key.id, day, api_key.daily_quota],
+    )?;
+    Ok(updated == 1)
+}
@@ -1,27 +1,142 @@
-use super::db_backup::get_initial_insert_statements;
-use rusqlite::{params, Connection, Result};
+use super::db_backup::{
+    get_initial_benefit_rules_statements, get_initial_economic_indices_statements,
+    get_initial_income_tax_exemptions_statements, get_initial_insert_statements,
+    get_initial_micro_enterprise_rates_statements, get_initial_other_income_rates_statements,
+    get_initial_other_income_thresholds_statements, get_initial_pfa_thresholds_statements,
+};
+use crate::messages::{render_message, Language};
+use crate::metrics::{record_tax_rates_cache_lookup, DB_QUERY_DURATION_SECONDS};
+use crate::models::validation::ValidationReport;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
 use std::fmt::Formatter;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use utoipa::ToSchema;
 
 #[derive(Debug)]
 pub enum TaxRateError {
     NotFound,
+    BenefitRuleNotFound(String),
+    // The rate of an other income category, for the year.
+    CategoryRateNotFound(String, u32),
+    ThresholdsNotFound,
+    IndicesNotFound(u32),
     DatabaseError(String),
+    // The database could not be opened at all.
+    DatabaseUnavailable(String),
+    // The request is valid on its own but can't be calculated with the rates, such as a total
+    // cost lower than the cost of the benefits.
+    InvalidInput(ValidationReport),
 }
 
-impl fmt::Display for TaxRateError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+impl TaxRateError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            TaxRateError::NotFound => "tax_rates_not_found",
+            TaxRateError::BenefitRuleNotFound(_) => "benefit_rule_not_found",
+            TaxRateError::CategoryRateNotFound(..) => "category_rate_not_found",
+            TaxRateError::ThresholdsNotFound => "thresholds_not_found",
+            TaxRateError::IndicesNotFound(_) => "indices_not_found",
+            TaxRateError::DatabaseError(_) | TaxRateError::DatabaseUnavailable(_) => {
+                "database_error"
+            }
+            TaxRateError::InvalidInput(_) => "invalid_input",
+        }
+    }
+
+    pub fn localized_message(&self, language: Language) -> String {
+        let value = match self {
+            TaxRateError::InvalidInput(ref report) => {
+                return report
+                    .errors
+                    .iter()
+                    .map(|error| {
+                        render_message(language, error.code, error.label, error.value.as_deref())
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+            }
+            TaxRateError::NotFound | TaxRateError::ThresholdsNotFound => None,
+            TaxRateError::BenefitRuleNotFound(ref benefit_type) => Some(benefit_type.clone()),
+            TaxRateError::CategoryRateNotFound(ref category, year) => {
+                return render_message(language, self.code(), "", Some(category))
+                    .replace("{year}", &year.to_string());
+            }
+            TaxRateError::IndicesNotFound(year) => Some(year.to_string()),
+            TaxRateError::DatabaseError(ref err) | TaxRateError::DatabaseUnavailable(ref err) => {
+                Some(err.clone())
+            }
+        };
+        render_message(language, self.code(), "", value.as_deref())
+    }
+}
+
+impl From<rusqlite::Error> for TaxRateError {
+    fn from(error: rusqlite::Error) -> Self {
+        TaxRateError::DatabaseError(error.to_string())
+    }
+}
+
+// The response sent back for an error, in the language of the request: an input that can't be
+// calculated is a 422 with the report, missing rows are a 404, a database that could not be
+// read or written a 500 and one that could not be opened a 503.
+pub struct LocalizedError(pub TaxRateError, pub Language);
+
+impl TaxRateError {
+    pub fn localized(self, language: Language) -> LocalizedError {
+        LocalizedError(self, language)
+    }
+
+    // Points the fields of an invalid input to the part of the request it came from, such as
+    // a month of the year.
+    pub fn within(self, prefix: &str) -> Self {
         match self {
-            TaxRateError::NotFound => write!(f, "Tax rates not found for the specified year."),
-            TaxRateError::DatabaseError(ref err) => write!(f, "Database error: {}", err),
+            TaxRateError::InvalidInput(mut report) => {
+                for error in report.errors.iter_mut() {
+                    error.field = format!("{prefix}.{}", error.field);
+                }
+                TaxRateError::InvalidInput(report)
+            }
+            error => error,
         }
     }
 }
 
+impl IntoResponse for LocalizedError {
+    fn into_response(self) -> Response {
+        let LocalizedError(error, language) = self;
+        if let TaxRateError::InvalidInput(report) = error {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response();
+        }
+        let status = match error {
+            TaxRateError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            TaxRateError::DatabaseUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            _ => StatusCode::NOT_FOUND,
+        };
+        (status, error.localized_message(language)).into_response()
+    }
+}
+
+impl fmt::Display for TaxRateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.localized_message(Language::English))
+    }
+}
+
 impl Error for TaxRateError {}
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TaxRates {
     pub year: i32,
     pub income_tax: f64,
@@ -30,8 +145,109 @@ pub struct TaxRates {
     pub insurance_contribution: f64,
 }
 
+// An employee matching the conditions pays no income tax, as long as the brute income of the
+// month stays within the limit.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeTaxExemption {
+    pub exemption_type: String,
+    pub max_age: Option<u32>,
+    pub requires_disability: bool,
+    pub max_brute_income: Option<f64>,
+}
+
+// The yearly contribution thresholds of the self-employed, in minimum wages.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PfaThresholds {
+    pub minimum_wage: f64,
+    // CAS is due on 12 minimum wages from the lower threshold and on 24 from the upper one.
+    pub cas_lower_wages: f64,
+    pub cas_upper_wages: f64,
+    // CASS is due on the net income, within these bounds.
+    pub cass_min_wages: f64,
+    pub cass_max_wages: f64,
+}
+
+// The yearly rates of a micro-enterprise and of the dividends paid to its associate.
+#[derive(Debug)]
+pub struct MicroEnterpriseRates {
+    pub turnover_tax: f64,
+    pub dividend_tax: f64,
+    // The associate is employed at least on the minimum wage, monthly.
+    pub minimum_wage: f64,
+    // CASS on dividends is due on 6, 12 or 24 minimum wages depending on the bracket reached.
+    pub cass_lower_wages: f64,
+    pub cass_middle_wages: f64,
+    pub cass_upper_wages: f64,
+}
+
+// CASS on rental, investment and dividend income is due on 6, 12 or 24 minimum wages depending
+// on the bracket reached by their yearly total.
+#[derive(Debug)]
+pub struct OtherIncomeThresholds {
+    pub minimum_wage: f64,
+    pub cass_lower_wages: f64,
+    pub cass_middle_wages: f64,
+    pub cass_upper_wages: f64,
+}
+
+// The income tax of a category of other income, due on the income minus the flat deduction.
+#[derive(Debug)]
+pub struct OtherIncomeRate {
+    pub category: String,
+    pub income_tax: f64,
+    pub flat_deduction: f64,
+    // Taxes withheld by the payer are not owed again on the tax return.
+    pub withheld_at_source: bool,
+}
+
+// The price and wage levels of a year, used to restate amounts between years.
+#[derive(Debug)]
+pub struct EconomicIndices {
+    // The consumer price index, 100 in 2023.
+    pub cpi: f64,
+    pub average_gross_wage: f64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BenefitRule {
+    pub benefit_type: String,
+    pub cas: bool,
+    pub cass: bool,
+    pub income_tax: bool,
+    pub cam: bool,
+}
+
+// Bumped whenever setup_db changes the tables or the seeded rows, stored in the database as
+// its user_version.
+pub const SCHEMA_VERSION: i32 = 16;
+
+// Inserts the initial rows of a table when it is empty, and again when the database was set up
+// by an older version so that corrected rows reach existing databases.
+fn seed_table(
+    conn: &Connection,
+    table: &str,
+    statements: Vec<String>,
+    upgrading: bool,
+) -> Result<()> {
+    let row_count: i64 = conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
+        row.get(0)
+    })?;
+    if row_count == 0 || upgrading {
+        for statement in statements {
+            tracing::debug!(statement, "Executing statement");
+            conn.execute(statement.as_str(), [])?;
+        }
+    }
+    Ok(())
+}
+
 // Function to create the table and insert some data if it does not exist.
 pub fn setup_db(conn: &Connection) -> Result<()> {
+    let stored_version: i32 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    let upgrading = stored_version < SCHEMA_VERSION;
     conn.execute(
         "CREATE TABLE IF NOT EXISTS tax_rates (
             year INTEGER PRIMARY KEY,
@@ -43,23 +259,253 @@ pub fn setup_db(conn: &Connection) -> Result<()> {
         [],
     )?;
 
-    let tax_rates_row_count: i64 =
-        conn.query_row("SELECT COUNT(*) FROM tax_rates", [], |row| row.get(0))?;
+    seed_table(
+        conn,
+        "tax_rates",
+        get_initial_insert_statements(),
+        upgrading,
+    )?;
 
-    if tax_rates_row_count == 0 {
-        // Table is empty, insert initial data.
-        let statements = get_initial_insert_statements();
-        for statement in statements {
-            println!("[DEBUG]: Executing statement: {}", statement);
-            conn.execute(statement.as_str(), [])?;
-        }
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS benefit_rules (
+            year INTEGER NOT NULL,
+            benefit_type TEXT NOT NULL,
+            cas INTEGER NOT NULL,
+            cass INTEGER NOT NULL,
+            income_tax INTEGER NOT NULL,
+            cam INTEGER NOT NULL,
+            PRIMARY KEY (year, benefit_type)
+        )",
+        [],
+    )?;
+
+    seed_table(
+        conn,
+        "benefit_rules",
+        get_initial_benefit_rules_statements(),
+        upgrading,
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS income_tax_exemptions (
+            year INTEGER NOT NULL,
+            exemption_type TEXT NOT NULL,
+            max_age INTEGER,
+            requires_disability INTEGER NOT NULL,
+            max_brute_income REAL,
+            PRIMARY KEY (year, exemption_type)
+        )",
+        [],
+    )?;
+
+    seed_table(
+        conn,
+        "income_tax_exemptions",
+        get_initial_income_tax_exemptions_statements(),
+        upgrading,
+    )?;
+
+    // Rates changing during a year, they apply from the given month to the end of the year
+    // or to the next change.
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS tax_rate_changes (
+            year INTEGER NOT NULL,
+            from_month INTEGER NOT NULL,
+            income_tax REAL NOT NULL,
+            social_security REAL NOT NULL,
+            health_insurance REAL NOT NULL,
+            insurance_contribution REAL NOT NULL,
+            PRIMARY KEY (year, from_month)
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS pfa_thresholds (
+            year INTEGER PRIMARY KEY,
+            minimum_wage REAL NOT NULL,
+            cas_lower_wages REAL NOT NULL,
+            cas_upper_wages REAL NOT NULL,
+            cass_min_wages REAL NOT NULL,
+            cass_max_wages REAL NOT NULL
+        )",
+        [],
+    )?;
+
+    seed_table(
+        conn,
+        "pfa_thresholds",
+        get_initial_pfa_thresholds_statements(),
+        upgrading,
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS micro_enterprise_rates (
+            year INTEGER PRIMARY KEY,
+            turnover_tax REAL NOT NULL,
+            dividend_tax REAL NOT NULL,
+            minimum_wage REAL NOT NULL,
+            cass_lower_wages REAL NOT NULL,
+            cass_middle_wages REAL NOT NULL,
+            cass_upper_wages REAL NOT NULL
+        )",
+        [],
+    )?;
+
+    seed_table(
+        conn,
+        "micro_enterprise_rates",
+        get_initial_micro_enterprise_rates_statements(),
+        upgrading,
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS other_income_thresholds (
+            year INTEGER PRIMARY KEY,
+            minimum_wage REAL NOT NULL,
+            cass_lower_wages REAL NOT NULL,
+            cass_middle_wages REAL NOT NULL,
+            cass_upper_wages REAL NOT NULL
+        )",
+        [],
+    )?;
+
+    seed_table(
+        conn,
+        "other_income_thresholds",
+        get_initial_other_income_thresholds_statements(),
+        upgrading,
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS other_income_rates (
+            year INTEGER NOT NULL,
+            category TEXT NOT NULL,
+            income_tax REAL NOT NULL,
+            flat_deduction REAL NOT NULL,
+            withheld_at_source INTEGER NOT NULL,
+            PRIMARY KEY (year, category)
+        )",
+        [],
+    )?;
+
+    seed_table(
+        conn,
+        "other_income_rates",
+        get_initial_other_income_rates_statements(),
+        upgrading,
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS economic_indices (
+            year INTEGER PRIMARY KEY,
+            cpi REAL NOT NULL,
+            average_gross_wage REAL NOT NULL
+        )",
+        [],
+    )?;
+
+    seed_table(
+        conn,
+        "economic_indices",
+        get_initial_economic_indices_statements(),
+        upgrading,
+    )?;
+
+    // Keys issued to partners, only the SHA-256 hash of a key is stored.
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS api_keys (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            key_hash TEXT NOT NULL UNIQUE,
+            prefix TEXT NOT NULL,
+            daily_quota INTEGER NOT NULL,
+            requests_per_minute INTEGER NOT NULL,
+            created_at TEXT NOT NULL,
+            revoked_at TEXT
+        )",
+        [],
+    )?;
+
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS api_key_usage (
+            key_id INTEGER NOT NULL REFERENCES api_keys (id),
+            day TEXT NOT NULL,
+            requests INTEGER NOT NULL,
+            PRIMARY KEY (key_id, day)
+        )",
+        [],
+    )?;
+
+    // Calculations shared through a link, with the rates and rules they were computed with.
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS shared_calculations (
+            id TEXT PRIMARY KEY,
+            input TEXT NOT NULL,
+            rules TEXT NOT NULL,
+            results TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            expires_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+    // Before version 11 only the yearly tax rates were kept.
+    let has_tax_rates_column: bool = conn.query_row(
+        "SELECT COUNT(*) > 0 FROM pragma_table_info('shared_calculations')
+         WHERE name = 'tax_rates'",
+        [],
+        |row| row.get(0),
+    )?;
+    if has_tax_rates_column {
+        conn.execute(
+            "ALTER TABLE shared_calculations RENAME COLUMN tax_rates TO rules",
+            [],
+        )?;
     }
 
+    conn.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+    // The seeds may have changed the rates read before.
+    TAX_RATES_CACHE.lock().unwrap().clear();
+
     Ok(())
 }
 
+// Function to query the schema version the database was set up with.
+pub fn get_schema_version(conn: &Connection) -> Result<i32, TaxRateError> {
+    conn.pragma_query_value(None, "user_version", |row| row.get(0))
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))
+}
+
+// Tax rates rarely change, so they are kept in memory once read. The cache is cleared when the
+// database is set up, and an entry is read again after the TTL so that edits made to the
+// database while the server runs are picked up.
+static TAX_RATES_CACHE: LazyLock<Mutex<HashMap<u32, (Instant, TaxRates)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+const TAX_RATES_CACHE_TTL: Duration = Duration::from_secs(60);
+
+// Function to query the tax rates for a specific year, served from the cache when possible.
+pub fn get_cached_tax_rates(conn: &Connection, year: u32) -> Result<TaxRates, TaxRateError> {
+    if let Some((read_at, tax_rates)) = TAX_RATES_CACHE.lock().unwrap().get(&year) {
+        if read_at.elapsed() < TAX_RATES_CACHE_TTL {
+            record_tax_rates_cache_lookup(true);
+            return Ok(tax_rates.clone());
+        }
+    }
+
+    record_tax_rates_cache_lookup(false);
+    let tax_rates = get_tax_rates(conn, year)?;
+    TAX_RATES_CACHE
+        .lock()
+        .unwrap()
+        .insert(year, (Instant::now(), tax_rates.clone()));
+    Ok(tax_rates)
+}
+
 // Function to query the tax rates for a specific year.
 pub fn get_tax_rates(conn: &Connection, year: u32) -> Result<TaxRates, TaxRateError> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_tax_rates"])
+        .start_timer();
     let mut stmt = conn
         .prepare(
             "SELECT year, income_tax, social_security, health_insurance, insurance_contribution
@@ -81,3 +527,237 @@ pub fn get_tax_rates(conn: &Connection, year: u32) -> Result<TaxRates, TaxRateEr
 
     Ok(tax_rates)
 }
+
+// Function to query the tax rates in force in a month, a change during the year takes over
+// the yearly rates from its first month.
+pub fn get_tax_rates_for_month(
+    conn: &Connection,
+    year: u32,
+    month: u32,
+) -> Result<TaxRates, TaxRateError> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_tax_rates_for_month"])
+        .start_timer();
+    let change = conn
+        .query_row(
+            "SELECT year, income_tax, social_security, health_insurance, insurance_contribution
+         FROM tax_rate_changes WHERE year = ?1 AND from_month <= ?2
+         ORDER BY from_month DESC LIMIT 1",
+            params![year, month],
+            |row| {
+                Ok(TaxRates {
+                    year: row.get(0)?,
+                    income_tax: row.get(1)?,
+                    social_security: row.get(2)?,
+                    health_insurance: row.get(3)?,
+                    insurance_contribution: row.get(4)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    match change {
+        Some(tax_rates) => Ok(tax_rates),
+        None => get_cached_tax_rates(conn, year),
+    }
+}
+
+// Function to query the contributions that apply to benefits in kind for a specific year.
+pub fn get_benefit_rules(conn: &Connection, year: u32) -> Result<Vec<BenefitRule>, TaxRateError> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_benefit_rules"])
+        .start_timer();
+    let mut stmt = conn
+        .prepare(
+            "SELECT benefit_type, cas, cass, income_tax, cam
+         FROM benefit_rules WHERE year = ?1",
+        )
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    let benefit_rules = stmt
+        .query_map(params![year], |row| {
+            Ok(BenefitRule {
+                benefit_type: row.get(0)?,
+                cas: row.get(1)?,
+                cass: row.get(2)?,
+                income_tax: row.get(3)?,
+                cam: row.get(4)?,
+            })
+        })
+        .and_then(|rows| rows.collect::<Result<Vec<_>>>())
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    Ok(benefit_rules)
+}
+
+// Function to query the income tax exemptions for a specific year, the ones without an income
+// limit first.
+pub fn get_income_tax_exemptions(
+    conn: &Connection,
+    year: u32,
+) -> Result<Vec<IncomeTaxExemption>, TaxRateError> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_income_tax_exemptions"])
+        .start_timer();
+    let mut stmt = conn
+        .prepare(
+            "SELECT exemption_type, max_age, requires_disability, max_brute_income
+         FROM income_tax_exemptions WHERE year = ?1
+         ORDER BY max_brute_income IS NOT NULL, exemption_type",
+        )
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    let exemptions = stmt
+        .query_map(params![year], |row| {
+            Ok(IncomeTaxExemption {
+                exemption_type: row.get(0)?,
+                max_age: row.get(1)?,
+                requires_disability: row.get(2)?,
+                max_brute_income: row.get(3)?,
+            })
+        })
+        .and_then(|rows| rows.collect::<Result<Vec<_>>>())
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    Ok(exemptions)
+}
+
+// Function to query the contribution thresholds of the self-employed for a specific year.
+pub fn get_pfa_thresholds(conn: &Connection, year: u32) -> Result<PfaThresholds, TaxRateError> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_pfa_thresholds"])
+        .start_timer();
+    let mut stmt = conn
+        .prepare(
+            "SELECT minimum_wage, cas_lower_wages, cas_upper_wages, cass_min_wages, cass_max_wages
+         FROM pfa_thresholds WHERE year = ?1",
+        )
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    let thresholds = stmt
+        .query_row(params![year], |row| {
+            Ok(PfaThresholds {
+                minimum_wage: row.get(0)?,
+                cas_lower_wages: row.get(1)?,
+                cas_upper_wages: row.get(2)?,
+                cass_min_wages: row.get(3)?,
+                cass_max_wages: row.get(4)?,
+            })
+        })
+        .map_err(|_| TaxRateError::ThresholdsNotFound)?;
+
+    Ok(thresholds)
+}
+
+// Function to query the micro-enterprise and dividend rates for a specific year.
+pub fn get_micro_enterprise_rates(
+    conn: &Connection,
+    year: u32,
+) -> Result<MicroEnterpriseRates, TaxRateError> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_micro_enterprise_rates"])
+        .start_timer();
+    let mut stmt = conn
+        .prepare(
+            "SELECT turnover_tax, dividend_tax, minimum_wage, cass_lower_wages, cass_middle_wages,
+            cass_upper_wages
+         FROM micro_enterprise_rates WHERE year = ?1",
+        )
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    let rates = stmt
+        .query_row(params![year], |row| {
+            Ok(MicroEnterpriseRates {
+                turnover_tax: row.get(0)?,
+                dividend_tax: row.get(1)?,
+                minimum_wage: row.get(2)?,
+                cass_lower_wages: row.get(3)?,
+                cass_middle_wages: row.get(4)?,
+                cass_upper_wages: row.get(5)?,
+            })
+        })
+        .map_err(|_| TaxRateError::ThresholdsNotFound)?;
+
+    Ok(rates)
+}
+
+// Function to query the CASS brackets of the other income for a specific year.
+pub fn get_other_income_thresholds(
+    conn: &Connection,
+    year: u32,
+) -> Result<OtherIncomeThresholds, TaxRateError> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_other_income_thresholds"])
+        .start_timer();
+    let mut stmt = conn
+        .prepare(
+            "SELECT minimum_wage, cass_lower_wages, cass_middle_wages, cass_upper_wages
+         FROM other_income_thresholds WHERE year = ?1",
+        )
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    let thresholds = stmt
+        .query_row(params![year], |row| {
+            Ok(OtherIncomeThresholds {
+                minimum_wage: row.get(0)?,
+                cass_lower_wages: row.get(1)?,
+                cass_middle_wages: row.get(2)?,
+                cass_upper_wages: row.get(3)?,
+            })
+        })
+        .map_err(|_| TaxRateError::ThresholdsNotFound)?;
+
+    Ok(thresholds)
+}
+
+// Function to query the income tax of every category of other income for a specific year.
+pub fn get_other_income_rates(
+    conn: &Connection,
+    year: u32,
+) -> Result<Vec<OtherIncomeRate>, TaxRateError> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_other_income_rates"])
+        .start_timer();
+    let mut stmt = conn
+        .prepare(
+            "SELECT category, income_tax, flat_deduction, withheld_at_source
+         FROM other_income_rates WHERE year = ?1",
+        )
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    let rates = stmt
+        .query_map(params![year], |row| {
+            Ok(OtherIncomeRate {
+                category: row.get(0)?,
+                income_tax: row.get(1)?,
+                flat_deduction: row.get(2)?,
+                withheld_at_source: row.get(3)?,
+            })
+        })
+        .and_then(|rows| rows.collect::<Result<Vec<_>>>())
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    Ok(rates)
+}
+
+// Function to query the price and wage indices of a specific year.
+pub fn get_economic_indices(conn: &Connection, year: u32) -> Result<EconomicIndices, TaxRateError> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_economic_indices"])
+        .start_timer();
+    let mut stmt = conn
+        .prepare("SELECT cpi, average_gross_wage FROM economic_indices WHERE year = ?1")
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+
+    let indices = stmt
+        .query_row(params![year], |row| {
+            Ok(EconomicIndices {
+                cpi: row.get(0)?,
+                average_gross_wage: row.get(1)?,
+            })
+        })
+        .map_err(|_| TaxRateError::IndicesNotFound(year))?;
+
+    Ok(indices)
+}
@@ -6,10 +6,14 @@ pub fn get_current_year() -> u32 {
 
 pub fn get_initial_insert_statements() -> Vec<String> {
     vec![
+        // 2026.
+        "INSERT INTO tax_rates (year, income_tax, social_security, health_insurance, insurance_contribution)
+              VALUES (2026, 0.10, 0.25, 0.1, 0.0225)
+              ON CONFLICT(year) DO NOTHING;".to_string(),
         // 2025.
-        format!("INSERT INTO tax_rates (year, income_tax, social_security, health_insurance, insurance_contribution)
-              VALUES ({}, 0.10, 0.25, 0.1, 0.0225)
-              ON CONFLICT(year) DO NOTHING;", get_current_year()),
+        "INSERT INTO tax_rates (year, income_tax, social_security, health_insurance, insurance_contribution)
+              VALUES (2025, 0.10, 0.25, 0.1, 0.0225)
+              ON CONFLICT(year) DO NOTHING;".to_string(),
         // 2024.
         "INSERT INTO tax_rates (year, income_tax, social_security, health_insurance, insurance_contribution)
               VALUES (2024, 0.10, 0.25, 0.1, 0.0225)
@@ -20,3 +24,136 @@ pub fn get_initial_insert_statements() -> Vec<String> {
               ON CONFLICT(year) DO NOTHING;".to_string(),
     ]
 }
+
+pub fn get_initial_benefit_rules_statements() -> Vec<String> {
+    // Meal and holiday vouchers are subject to CASS and income tax but not CAS,
+    // other benefits in kind are treated like salary.
+    let mut statements = Vec::new();
+    for year in [2026, 2025, 2024, 2023] {
+        for (benefit_type, cas, cass, income_tax, cam) in [
+            ("MEAL_VOUCHER", 0, 1, 1, 0),
+            ("HOLIDAY_VOUCHER", 0, 1, 1, 0),
+            ("OTHER", 1, 1, 1, 1),
+        ] {
+            statements.push(format!(
+                "INSERT INTO benefit_rules (year, benefit_type, cas, cass, income_tax, cam)
+              VALUES ({year}, '{benefit_type}', {cas}, {cass}, {income_tax}, {cam})
+              ON CONFLICT(year, benefit_type) DO UPDATE SET cas = excluded.cas,
+                cass = excluded.cass, income_tax = excluded.income_tax, cam = excluded.cam;"
+            ));
+        }
+    }
+    statements
+}
+
+pub fn get_initial_income_tax_exemptions_statements() -> Vec<String> {
+    // Employees with a disability pay no income tax, employees under 26 neither while their
+    // brute monthly income stays within the limit.
+    let mut statements = Vec::new();
+    for year in [2026, 2025, 2024, 2023] {
+        for (exemption_type, max_age, requires_disability, max_brute_income) in [
+            ("DISABILITY", "NULL", 1, "NULL"),
+            ("UNDER_26", "25", 0, "6050"),
+        ] {
+            statements.push(format!(
+                "INSERT INTO income_tax_exemptions (year, exemption_type, max_age, requires_disability, max_brute_income)
+              VALUES ({year}, '{exemption_type}', {max_age}, {requires_disability}, {max_brute_income})
+              ON CONFLICT(year, exemption_type) DO UPDATE SET max_age = excluded.max_age,
+                requires_disability = excluded.requires_disability,
+                max_brute_income = excluded.max_brute_income;"
+            ));
+        }
+    }
+    statements
+}
+
+pub fn get_initial_pfa_thresholds_statements() -> Vec<String> {
+    // The minimum wage used for the yearly declaration, CAS is due from 12 and 24 minimum
+    // wages and CASS is due between 6 and 60 minimum wages, 72 from 2025.
+    let mut statements = Vec::new();
+    for (year, minimum_wage, cass_max_wages) in [
+        (2026, 4050, 72),
+        (2025, 4050, 72),
+        (2024, 3700, 60),
+        (2023, 3000, 60),
+    ] {
+        statements.push(format!(
+            "INSERT INTO pfa_thresholds (year, minimum_wage, cas_lower_wages, cas_upper_wages, cass_min_wages, cass_max_wages)
+              VALUES ({year}, {minimum_wage}, 12, 24, 6, {cass_max_wages})
+              ON CONFLICT(year) DO UPDATE SET minimum_wage = excluded.minimum_wage,
+                cass_max_wages = excluded.cass_max_wages;"
+        ));
+    }
+    statements
+}
+
+pub fn get_initial_micro_enterprise_rates_statements() -> Vec<String> {
+    // 1% turnover tax, the dividend tax went from 8% to 10% in 2025 and 16% in 2026, CASS on
+    // dividends is due on 6, 12 or 24 minimum wages.
+    let mut statements = Vec::new();
+    for (year, dividend_tax, minimum_wage) in [
+        (2026, 0.16, 4050),
+        (2025, 0.10, 4050),
+        (2024, 0.08, 3700),
+        (2023, 0.08, 3000),
+    ] {
+        statements.push(format!(
+            "INSERT INTO micro_enterprise_rates (year, turnover_tax, dividend_tax, minimum_wage, cass_lower_wages, cass_middle_wages, cass_upper_wages)
+              VALUES ({year}, 0.01, {dividend_tax}, {minimum_wage}, 6, 12, 24)
+              ON CONFLICT(year) DO UPDATE SET dividend_tax = excluded.dividend_tax,
+                minimum_wage = excluded.minimum_wage;"
+        ));
+    }
+    statements
+}
+
+pub fn get_initial_other_income_thresholds_statements() -> Vec<String> {
+    // CASS on rental, investment and dividend income is due on 6, 12 or 24 minimum wages.
+    let mut statements = Vec::new();
+    for (year, minimum_wage) in [(2026, 4050), (2025, 4050), (2024, 3700), (2023, 3000)] {
+        statements.push(format!(
+            "INSERT INTO other_income_thresholds (year, minimum_wage, cass_lower_wages, cass_middle_wages, cass_upper_wages)
+              VALUES ({year}, {minimum_wage}, 6, 12, 24)
+              ON CONFLICT(year) DO NOTHING;"
+        ));
+    }
+    statements
+}
+
+pub fn get_initial_other_income_rates_statements() -> Vec<String> {
+    // Rents are taxed at 10% after a flat deduction of 20%, investment gains at 10%. The
+    // dividend tax, withheld by the company, went from 8% to 10% in 2025 and 16% in 2026.
+    let mut statements = Vec::new();
+    for (year, dividend_tax) in [(2026, 0.16), (2025, 0.10), (2024, 0.08), (2023, 0.08)] {
+        for (category, income_tax, flat_deduction, withheld_at_source) in [
+            ("RENTAL", 0.1, 0.2, 0),
+            ("INVESTMENT", 0.1, 0.0, 0),
+            ("DIVIDENDS", dividend_tax, 0.0, 1),
+        ] {
+            statements.push(format!(
+                "INSERT INTO other_income_rates (year, category, income_tax, flat_deduction, withheld_at_source)
+              VALUES ({year}, '{category}', {income_tax}, {flat_deduction}, {withheld_at_source})
+              ON CONFLICT(year, category) DO UPDATE SET income_tax = excluded.income_tax;"
+            ));
+        }
+    }
+    statements
+}
+
+pub fn get_initial_economic_indices_statements() -> Vec<String> {
+    // The yearly average consumer price index, 100 in 2023, and the average gross wage.
+    let mut statements = Vec::new();
+    for (year, cpi, average_gross_wage) in [
+        (2025, 112.0, 9300),
+        (2024, 105.6, 8600),
+        (2023, 100.0, 7550),
+    ] {
+        statements.push(format!(
+            "INSERT INTO economic_indices (year, cpi, average_gross_wage)
+              VALUES ({year}, {cpi}, {average_gross_wage})
+              ON CONFLICT(year) DO UPDATE SET cpi = excluded.cpi,
+                average_gross_wage = excluded.average_gross_wage;"
+        ));
+    }
+    statements
+}
@@ -1,2 +1,4 @@
+pub mod api_keys;
 pub mod db;
 pub mod db_backup;
+pub mod shared_calculations;
@@ -0,0 +1,61 @@
+use crate::metrics::DB_QUERY_DURATION_SECONDS;
+use rusqlite::{params, Connection, OptionalExtension, Result};
+
+// The stored JSON documents of a shared calculation.
+#[derive(Debug)]
+pub struct StoredCalculation {
+    pub id: String,
+    pub input: String,
+    pub rules: String,
+    pub results: String,
+    pub created_at: String,
+    pub expires_at: String,
+}
+
+// Function to store a shared calculation for the given number of days, the expired ones are
+// removed at the same time.
+pub fn insert_shared_calculation(
+    conn: &Connection,
+    id: &str,
+    input: &str,
+    rules: &str,
+    results: &str,
+    ttl_days: u32,
+) -> Result<StoredCalculation> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["insert_shared_calculation"])
+        .start_timer();
+    conn.execute(
+        "DELETE FROM shared_calculations WHERE expires_at <= datetime('now')",
+        [],
+    )?;
+    conn.execute(
+        "INSERT INTO shared_calculations (id, input, rules, results, created_at, expires_at)
+         VALUES (?1, ?2, ?3, ?4, datetime('now'), datetime('now', ?5))",
+        params![id, input, rules, results, format!("+{ttl_days} days")],
+    )?;
+    get_shared_calculation(conn, id)?.ok_or(rusqlite::Error::QueryReturnedNoRows)
+}
+
+// Function to query a shared calculation, expired ones are not returned.
+pub fn get_shared_calculation(conn: &Connection, id: &str) -> Result<Option<StoredCalculation>> {
+    let _timer = DB_QUERY_DURATION_SECONDS
+        .with_label_values(&["get_shared_calculation"])
+        .start_timer();
+    conn.query_row(
+        "SELECT id, input, rules, results, created_at, expires_at
+         FROM shared_calculations WHERE id = ?1 AND expires_at > datetime('now')",
+        params![id],
+        |row| {
+            Ok(StoredCalculation {
+                id: row.get(0)?,
+                input: row.get(1)?,
+                rules: row.get(2)?,
+                results: row.get(3)?,
+                created_at: row.get(4)?,
+                expires_at: row.get(5)?,
+            })
+        },
+    )
+    .optional()
+}
@@ -0,0 +1,62 @@
+use axum::http::Request;
+use std::env;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::Span;
+use tracing_subscriber::EnvFilter;
+
+// Salaries are personal data, they only show up in the logs when LOG_SALARIES=true.
+static LOG_SALARIES: AtomicBool = AtomicBool::new(false);
+
+// Configured from the environment:
+// - LOG_LEVEL: a level or a filter such as "info,calven=debug", defaults to "info".
+// - LOG_FORMAT: "json" for one JSON object per line, anything else for plain text.
+// - LOG_SALARIES: "true" to log salary amounts instead of redacting them.
+pub fn init_tracing() {
+    let filter = env::var("LOG_LEVEL")
+        .ok()
+        .filter(|level| !level.trim().is_empty())
+        .and_then(|level| EnvFilter::try_new(level).ok())
+        .unwrap_or_else(|| EnvFilter::new("info"));
+    let json = env::var("LOG_FORMAT").is_ok_and(|format| format.eq_ignore_ascii_case("json"));
+    LOG_SALARIES.store(
+        env::var("LOG_SALARIES").is_ok_and(|value| value.eq_ignore_ascii_case("true")),
+        Ordering::Relaxed,
+    );
+
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter);
+    if json {
+        subscriber.json().init();
+    } else {
+        subscriber.init();
+    }
+}
+
+// The span wrapping every request, the request id comes from the x-request-id header
+// set by the request id layer.
+pub fn make_request_span<B>(request: &Request<B>) -> Span {
+    let request_id = request
+        .headers()
+        .get("x-request-id")
+        .and_then(|request_id| request_id.to_str().ok())
+        .unwrap_or_default();
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        request_id = %request_id,
+    )
+}
+
+// Wraps an amount so it is redacted when logged, unless salaries logging is enabled.
+pub struct Salary<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Display for Salary<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if LOG_SALARIES.load(Ordering::Relaxed) {
+            write!(f, "{:?}", self.0)
+        } else {
+            write!(f, "[redacted]")
+        }
+    }
+}
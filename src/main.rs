@@ -1,16 +1,38 @@
 use axum::Router;
 use rusqlite::Connection;
+use std::env;
+use std::process::ExitCode;
 use tokio::net::TcpListener;
 
 use crate::routes::taxes::taxes_router;
 use database::db::setup_db;
 use database::db_backup::get_current_year;
+use logging::init_tracing;
+use middleware::{apply_middleware, MiddlewareConfig};
+use routes::admin::admin_router;
 use routes::calculations::calculate_router;
-use routes::health::health_router;
+use routes::compare::compare_router;
+use routes::docs::docs_router;
+use routes::health::{health_router, probes_router};
+use routes::legacy::{legacy_health_router, legacy_router};
+use routes::metrics::metrics_router;
+use routes::micro_enterprise::micro_enterprise_router;
+use routes::other_income::other_income_router;
+use routes::payroll::payroll_router;
+use routes::real_income::real_income_router;
+use routes::shared_calculations::shared_calculations_router;
+use server::{serve, StartupError};
 
+mod auth;
 mod database;
+mod logging;
+mod messages;
+mod metrics;
+mod middleware;
 mod models;
+mod rate_limit;
 mod routes;
+mod server;
 mod services;
 mod utils;
 mod validators;
@@ -22,24 +44,52 @@ static SERVER_ADDRESS: &str = "0.0.0.0:8000";
 // TODO: Try refactor code to be more idiomatic.
 
 #[tokio::main]
-async fn main() -> Result<(), ()> {
-    println!("[INFO]: Current year is {}...", get_current_year());
-    println!("[INFO]: Set up the database...");
-    let conn =
-        Connection::open("./tax_rates.db").expect("Sqlite conn should be able to open. Cause");
-    setup_db(&conn).expect("Setup db should work. Cause");
-
-    println!("[INFO]: Create routers...");
-    let main_router = Router::new()
-        .merge(health_router())
+async fn main() -> ExitCode {
+    init_tracing();
+    match run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            tracing::error!(%error, "Startup failed");
+            ExitCode::from(error.exit_code())
+        }
+    }
+}
+
+async fn run() -> Result<(), StartupError> {
+    tracing::info!("Current year is {}...", get_current_year());
+    tracing::info!("Set up the database...");
+    let conn = Connection::open("./tax_rates.db").map_err(StartupError::DatabaseOpen)?;
+    setup_db(&conn).map_err(StartupError::Migration)?;
+
+    tracing::info!("Create routers...");
+    let v1_router = Router::new()
         .merge(calculate_router())
+        .merge(shared_calculations_router())
+        .merge(micro_enterprise_router())
+        .merge(other_income_router())
+        .merge(payroll_router())
+        .merge(real_income_router())
+        .merge(compare_router())
         .merge(taxes_router());
+    let api_router = Router::new().nest("/v1", v1_router).merge(legacy_router());
+    let main_router = Router::new()
+        .nest("/v1", health_router())
+        .merge(legacy_health_router())
+        .merge(probes_router())
+        .merge(docs_router())
+        .merge(metrics_router())
+        .merge(admin_router());
+    let middleware_config = MiddlewareConfig::from_env();
+    tracing::info!(?middleware_config, "Apply middleware...");
+    let main_router = apply_middleware(api_router, main_router, middleware_config);
 
-    let listener = TcpListener::bind(SERVER_ADDRESS).await.unwrap();
-    println!("----> LISTENING on {:?}\n", listener.local_addr().unwrap());
-    axum::serve(listener, main_router.into_make_service())
+    // The address can be overridden, e.g. to run a second instance next to the first.
+    let address = env::var("SERVER_ADDRESS").unwrap_or_else(|_| SERVER_ADDRESS.to_string());
+    let listener = TcpListener::bind(&address)
         .await
-        .unwrap();
-
-    Ok(())
+        .map_err(|error| StartupError::Bind(address, error))?;
+    if let Ok(local_address) = listener.local_addr() {
+        tracing::info!("LISTENING on {:?}", local_address);
+    }
+    serve(listener, main_router).await
 }
@@ -0,0 +1,439 @@
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::header::ACCEPT_LANGUAGE;
+use axum::http::request::Parts;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+
+use crate::validators::amounts::MAX_AMOUNT;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    Romanian,
+}
+
+impl Language {
+    pub(crate) fn from_str(language: &str) -> Option<Language> {
+        // Only the primary subtag matters, "ro-RO" and "ro" are the same for us.
+        let primary = language.trim().split(['-', '_']).next().unwrap_or_default();
+        match primary.to_lowercase().as_str() {
+            "en" => Some(Language::English),
+            "ro" => Some(Language::Romanian),
+            _ => None,
+        }
+    }
+
+    // Picks the supported language with the highest quality from an Accept-Language header.
+    pub fn from_accept_language(header: &str) -> Option<Language> {
+        let mut best: Option<(Language, f32)> = None;
+        for entry in header.split(',') {
+            let mut parts = entry.split(';');
+            let Some(language) = parts.next().and_then(Language::from_str) else {
+                continue;
+            };
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|quality| quality.trim().parse().ok())
+                .unwrap_or(1.0);
+            if best.is_none_or(|(_, best_quality)| quality > best_quality) {
+                best = Some((language, quality));
+            }
+        }
+        best.map(|(language, _)| language)
+    }
+}
+
+// The language is taken from the `lang` query parameter, then from the Accept-Language
+// header, and defaults to English.
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Language {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let from_query = parts.uri.query().and_then(|query| {
+            query
+                .split('&')
+                .filter_map(|param| param.split_once('='))
+                .find(|(key, _)| *key == "lang")
+                .and_then(|(_, value)| Language::from_str(value))
+        });
+        let from_header = || {
+            parts
+                .headers
+                .get(ACCEPT_LANGUAGE)
+                .and_then(|header| header.to_str().ok())
+                .and_then(Language::from_accept_language)
+        };
+
+        Ok(from_query.or_else(from_header).unwrap_or(Language::English))
+    }
+}
+
+// Message templates keyed by error code, `{label}`, `{value}` and `{max_amount}` are filled in
+// on render, `{year}` by the errors naming one.
+fn template(language: Language, code: &str) -> Option<&'static str> {
+    let template = match (language, code) {
+        (Language::English, "missing") => "Invalid or missing {label}.",
+        (Language::Romanian, "missing") => "Valoare lipsă sau invalidă pentru {label}.",
+        (Language::English, "negative_amount") => {
+            "Invalid {label} {value}, it should not be negative."
+        }
+        (Language::Romanian, "negative_amount") => {
+            "Valoare invalidă pentru {label}: {value}, nu poate fi negativă."
+        }
+        (Language::English, "invalid_amount") => {
+            "Invalid {label} {value}, expected an amount such as 4500.50 or 4.500,50."
+        }
+        (Language::Romanian, "invalid_amount") => {
+            "Valoare invalidă pentru {label}: {value}, se așteaptă o sumă precum 4.500,50 sau 4500.50."
+        }
+        (Language::English, "amount_too_large") => {
+            "Invalid {label} {value}, the maximum supported amount is {max_amount}."
+        }
+        (Language::Romanian, "amount_too_large") => {
+            "Valoare invalidă pentru {label}: {value}, suma maximă acceptată este {max_amount}."
+        }
+        (Language::English, "total_cost_too_low") => {
+            "Invalid {label} {value}, the benefits and payments alone cost the employer more."
+        }
+        (Language::Romanian, "total_cost_too_low") => {
+            "Valoare invalidă pentru {label}: {value}, beneficiile și plățile costă angajatorul mai mult."
+        }
+        (Language::English, "unsupported_income_type") => "Unsupported income type {value}.",
+        (Language::Romanian, "unsupported_income_type") => {
+            "Tipul de venit {value} nu este suportat."
+        }
+        (Language::English, "unsupported_currency") => "Currency {value} not supported.",
+        (Language::Romanian, "unsupported_currency") => "Moneda {value} nu este suportată.",
+        (Language::English, "unsupported_income_category") => {
+            "Unsupported income category {value}."
+        }
+        (Language::Romanian, "unsupported_income_category") => {
+            "Categoria de venit {value} nu este suportată."
+        }
+        (Language::English, "unsupported_benefit_type") => "Unsupported benefit type {value}.",
+        (Language::Romanian, "unsupported_benefit_type") => {
+            "Tipul de beneficiu {value} nu este suportat."
+        }
+        (Language::English, "invalid_hours") => "Invalid or missing {label}.",
+        (Language::Romanian, "invalid_hours") => "Valoare lipsă sau invalidă pentru {label}.",
+        (Language::English, "tax_rates_not_found") => "Tax rates not found for the specified year.",
+        (Language::Romanian, "tax_rates_not_found") => {
+            "Cotele de impozitare nu au fost găsite pentru anul specificat."
+        }
+        (Language::English, "benefit_rule_not_found") => {
+            "Benefit rule for {value} not found for the specified year."
+        }
+        (Language::Romanian, "benefit_rule_not_found") => {
+            "Regula pentru beneficiul {value} nu a fost găsită pentru anul specificat."
+        }
+        (Language::English, "category_rate_not_found") => {
+            "Tax rate for the {value} income category not found for the year {year}."
+        }
+        (Language::Romanian, "category_rate_not_found") => {
+            "Cota de impozitare pentru categoria de venit {value} nu a fost găsită pentru anul {year}."
+        }
+        (Language::English, "thresholds_not_found") => {
+            "Contribution thresholds not found for the specified year."
+        }
+        (Language::Romanian, "thresholds_not_found") => {
+            "Plafoanele contribuțiilor nu au fost găsite pentru anul specificat."
+        }
+        (Language::English, "indices_not_found") => {
+            "Price and wage indices not found for the year {value}."
+        }
+        (Language::Romanian, "indices_not_found") => {
+            "Indicii prețurilor și salariilor nu au fost găsiți pentru anul {value}."
+        }
+        (Language::English, "unsupported_for_income_type") => {
+            "The {label} field is not supported for the {value} income type."
+        }
+        (Language::Romanian, "unsupported_for_income_type") => {
+            "Câmpul {label} nu este suportat pentru tipul de venit {value}."
+        }
+        (Language::English, "set_for_the_whole_request") => {
+            "The {label} is set once for the whole request."
+        }
+        (Language::Romanian, "set_for_the_whole_request") => {
+            "Câmpul {label} se stabilește o singură dată pentru întreaga cerere."
+        }
+        (Language::English, "invalid_month_count") => {
+            "Invalid {label} {value}, expected the 12 months of the year."
+        }
+        (Language::Romanian, "invalid_month_count") => {
+            "Valoare invalidă pentru {label}: {value}, se așteaptă cele 12 luni ale anului."
+        }
+        (Language::English, "invalid_birth_date") => {
+            "Invalid {label} {value}, expected a past date such as 2001-05-20."
+        }
+        (Language::Romanian, "invalid_birth_date") => {
+            "Valoare invalidă pentru {label}: {value}, se așteaptă o dată din trecut precum 2001-05-20."
+        }
+        (Language::English, "invalid_cif") => "Invalid {label} {value}, expected 2 to 10 digits.",
+        (Language::Romanian, "invalid_cif") => {
+            "Valoare invalidă pentru {label}: {value}, se așteaptă între 2 și 10 cifre."
+        }
+        (Language::English, "invalid_cnp") => "Invalid {label} {value}, expected 13 digits.",
+        (Language::Romanian, "invalid_cnp") => {
+            "Valoare invalidă pentru {label}: {value}, se așteaptă 13 cifre."
+        }
+        (Language::English, "database_error") => "Database error: {value}",
+        (Language::Romanian, "database_error") => "Eroare a bazei de date: {value}",
+        (Language::English, "rate_limited") => {
+            "Too many requests, retry in {value} seconds."
+        }
+        (Language::Romanian, "rate_limited") => {
+            "Prea multe cereri, reîncercați în {value} secunde."
+        }
+        (Language::English, "invalid_api_key") => "Invalid or revoked API key.",
+        (Language::Romanian, "invalid_api_key") => "Cheie API invalidă sau revocată.",
+        (Language::English, "api_key_quota_exceeded") => {
+            "The daily quota of the API key is used up, retry in {value} seconds."
+        }
+        (Language::Romanian, "api_key_quota_exceeded") => {
+            "Cota zilnică a cheii API a fost epuizată, reîncercați în {value} secunde."
+        }
+        (Language::English, "api_key_not_found") => "No active API key with id {value}.",
+        (Language::Romanian, "api_key_not_found") => "Nu există o cheie API activă cu id-ul {value}.",
+        (Language::English, "shared_calculation_not_found") => {
+            "No calculation with id {value}, or it expired."
+        }
+        (Language::Romanian, "shared_calculation_not_found") => {
+            "Nu există un calcul cu id-ul {value}, sau a expirat."
+        }
+        (Language::English, "unauthorized") => "Missing or invalid admin token.",
+        (Language::Romanian, "unauthorized") => "Token de administrare lipsă sau invalid.",
+        (Language::English, "healthy") => "The server is healthy.",
+        (Language::Romanian, "healthy") => "Serverul funcționează corect.",
+        // Invalid values needing no explanation, such as a year, a count or a number of hours.
+        (
+            Language::English,
+            "invalid_year" | "invalid_custom_tax" | "invalid_count" | "invalid_month"
+            | "invalid_multiplier" | "invalid_working_hours" | "invalid_limit",
+        ) => "Invalid {label} {value}.",
+        (
+            Language::Romanian,
+            "invalid_year" | "invalid_custom_tax" | "invalid_count" | "invalid_month"
+            | "invalid_multiplier" | "invalid_working_hours" | "invalid_limit",
+        ) => "Valoare invalidă pentru {label}: {value}.",
+        _ => return None,
+    };
+    Some(template)
+}
+
+// Field labels used inside the messages.
+fn label(language: Language, label: &str) -> Option<&'static str> {
+    let label = match (language, label) {
+        (Language::English, "income") => "income",
+        (Language::Romanian, "income") => "venit",
+        (Language::English, "income_type") => "income type",
+        (Language::Romanian, "income_type") => "tipul de venit",
+        (Language::English, "currency") => "currency",
+        (Language::Romanian, "currency") => "moneda",
+        (Language::English, "income_category") => "income category",
+        (Language::Romanian, "income_category") => "categoria de venit",
+        (Language::English, "benefit_type") => "benefit type",
+        (Language::Romanian, "benefit_type") => "tipul beneficiului",
+        (Language::English, "benefit_amount") => "benefit amount",
+        (Language::Romanian, "benefit_amount") => "suma beneficiului",
+        (Language::English, "benefit_count") => "benefit count",
+        (Language::Romanian, "benefit_count") => "numărul de beneficii",
+        (Language::English, "bonus_amount") => "bonus amount",
+        (Language::Romanian, "bonus_amount") => "suma bonusului",
+        (Language::English, "one_off_payment_amount") => "one-off payment amount",
+        (Language::Romanian, "one_off_payment_amount") => "suma plății unice",
+        (Language::English, "overtime_hours") => "overtime hours",
+        (Language::Romanian, "overtime_hours") => "orele suplimentare",
+        (Language::English, "overtime_multiplier") => "overtime multiplier",
+        (Language::Romanian, "overtime_multiplier") => "multiplicatorul orelor suplimentare",
+        (Language::English, "working_hours") => "working hours",
+        (Language::Romanian, "working_hours") => "orele lucrătoare",
+        (Language::English, "custom_tax") => "custom tax",
+        (Language::Romanian, "custom_tax") => "taxa personalizată",
+        (Language::English, "months") => "number of months",
+        (Language::Romanian, "months") => "numărul de luni",
+        (Language::English, "budget") => "budget",
+        (Language::Romanian, "budget") => "buget",
+        (Language::English, "revenue") => "revenue",
+        (Language::Romanian, "revenue") => "venituri",
+        (Language::English, "expenses") => "expenses",
+        (Language::Romanian, "expenses") => "cheltuieli",
+        (Language::English, "benefits") => "benefits",
+        (Language::Romanian, "benefits") => "beneficii",
+        (Language::English, "bonuses") => "bonuses",
+        (Language::Romanian, "bonuses") => "bonusuri",
+        (Language::English, "overtime") => "overtime hours",
+        (Language::Romanian, "overtime") => "ore suplimentare",
+        (Language::English, "one_off_payments") => "one-off payments",
+        (Language::Romanian, "one_off_payments") => "plăți unice",
+        (Language::English, "birth_date") => "birth date",
+        (Language::Romanian, "birth_date") => "data nașterii",
+        (Language::English, "disability") => "disability",
+        (Language::Romanian, "disability") => "dizabilitate",
+        (Language::English, "incomes") => "incomes",
+        (Language::Romanian, "incomes") => "venituri",
+        (Language::English, "month") => "month",
+        (Language::Romanian, "month") => "luna",
+        (Language::English, "employees") => "employees",
+        (Language::Romanian, "employees") => "angajați",
+        (Language::English, "employee_name") => "employee name",
+        (Language::Romanian, "employee_name") => "numele angajatului",
+        (Language::English, "employer_name") => "employer name",
+        (Language::Romanian, "employer_name") => "numele angajatorului",
+        (Language::English, "cif") => "fiscal code",
+        (Language::Romanian, "cif") => "codul fiscal",
+        (Language::English, "cnp") => "personal numeric code",
+        (Language::Romanian, "cnp") => "CNP",
+        (Language::English, "deductions") => "deductions",
+        (Language::Romanian, "deductions") => "rețineri",
+        (Language::English, "salary") => "salary",
+        (Language::Romanian, "salary") => "salariu",
+        (Language::English, "year") => "year",
+        (Language::Romanian, "year") => "anul",
+        (Language::English, "api_key_name") => "API key name",
+        (Language::Romanian, "api_key_name") => "numele cheii API",
+        (Language::English, "daily_quota") => "daily quota",
+        (Language::Romanian, "daily_quota") => "cota zilnică",
+        (Language::English, "requests_per_minute") => "requests per minute",
+        (Language::Romanian, "requests_per_minute") => "cererile pe minut",
+        _ => return None,
+    };
+    Some(label)
+}
+
+// A code or a label without a translation is a bug, it is logged and rendered generically.
+pub fn render_message(
+    language: Language,
+    code: &str,
+    label_key: &str,
+    value: Option<&str>,
+) -> String {
+    let template = template(language, code).unwrap_or_else(|| {
+        tracing::error!(code, ?language, "No message template for the code");
+        match language {
+            Language::English => "Invalid {label} {value}.",
+            Language::Romanian => "Valoare invalidă pentru {label}: {value}.",
+        }
+    });
+    let mut message = template
+        .replace("{value}", value.unwrap_or_default())
+        .replace("{max_amount}", &MAX_AMOUNT.to_string());
+    if message.contains("{label}") {
+        let label = label(language, label_key).unwrap_or_else(|| {
+            tracing::error!(label = label_key, ?language, "No translation for the label");
+            "value"
+        });
+        message = message.replace("{label}", label);
+    }
+    message
+}
+
+// Labels for the fields of the calculation breakdown.
+pub fn breakdown_labels(language: Language) -> BTreeMap<&'static str, &'static str> {
+    let labels = match language {
+        Language::English => [
+            ("bruteIncome", "Gross income"),
+            ("netIncome", "Net income"),
+            ("cas", "Pension contribution (CAS)"),
+            ("cass", "Health contribution (CASS)"),
+            ("incomeTax", "Income tax"),
+            ("cam", "Work insurance contribution (CAM)"),
+            ("totalSalary", "Total employer cost"),
+            ("benefitsValue", "Benefits in kind"),
+            ("takeHome", "Take-home value"),
+            ("expenses", "Deductible expenses"),
+            ("exemption", "Income tax exemption"),
+        ],
+        Language::Romanian => [
+            ("bruteIncome", "Venit brut"),
+            ("netIncome", "Venit net"),
+            ("cas", "Contribuția la pensie (CAS)"),
+            ("cass", "Contribuția la sănătate (CASS)"),
+            ("incomeTax", "Impozit pe venit"),
+            ("cam", "Contribuția asiguratorie pentru muncă (CAM)"),
+            ("totalSalary", "Costul total al angajatorului"),
+            ("benefitsValue", "Beneficii în natură"),
+            ("takeHome", "Valoare primită"),
+            ("expenses", "Cheltuieli deductibile"),
+            ("exemption", "Scutire de impozit pe venit"),
+        ],
+    };
+    labels.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{label, render_message, template, Language};
+    use std::fs;
+    use std::path::Path;
+
+    static LANGUAGES: [Language; 2] = [Language::English, Language::Romanian];
+
+    // Codes only emitted outside the validators.
+    static OTHER_CODES: [&str; 15] = [
+        "tax_rates_not_found",
+        "benefit_rule_not_found",
+        "category_rate_not_found",
+        "thresholds_not_found",
+        "indices_not_found",
+        "total_cost_too_low",
+        "database_error",
+        "rate_limited",
+        "invalid_api_key",
+        "api_key_quota_exceeded",
+        "api_key_not_found",
+        "shared_calculation_not_found",
+        "unauthorized",
+        "healthy",
+        "missing",
+    ];
+
+    // Request fields named in the validators that are never used as a label.
+    static FIELDS: [&str; 3] = ["name", "from", "to"];
+
+    // Every lowercase identifier written as a string literal in the validators, which are
+    // the error codes, the labels and some field names.
+    fn validator_literals() -> Vec<String> {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/validators");
+        let mut literals = Vec::new();
+        for entry in fs::read_dir(dir).unwrap() {
+            let source = fs::read_to_string(entry.unwrap().path()).unwrap();
+            for (index, literal) in source.split('"').enumerate() {
+                if index % 2 == 1
+                    && !literal.is_empty()
+                    && literal.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+                {
+                    literals.push(literal.to_string());
+                }
+            }
+        }
+        literals
+    }
+
+    #[test]
+    fn every_code_and_label_is_translated() {
+        for language in LANGUAGES {
+            for code in OTHER_CODES {
+                assert!(template(language, code).is_some(), "{code} in {language:?}");
+            }
+            for literal in validator_literals() {
+                if FIELDS.contains(&literal.as_str()) {
+                    continue;
+                }
+                assert!(
+                    template(language, &literal).is_some() || label(language, &literal).is_some(),
+                    "{literal} has no template or label in {language:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn amount_too_large_gives_the_maximum() {
+        assert_eq!(
+            render_message(Language::English, "amount_too_large", "income", Some("2e8")),
+            "Invalid income 2e8, the maximum supported amount is 100000000."
+        );
+    }
+}
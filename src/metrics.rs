@@ -0,0 +1,124 @@
+use axum::extract::{MatchedPath, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use prometheus::{
+    register_gauge, register_histogram_vec, register_int_counter_vec, Encoder, Gauge, HistogramVec,
+    IntCounterVec, TextEncoder,
+};
+use std::sync::LazyLock;
+use std::time::Instant;
+
+static HTTP_REQUESTS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "http_requests_total",
+        "Number of HTTP requests by route, method and status.",
+        &["route", "method", "status"]
+    )
+    .expect("The metric should only be registered once")
+});
+
+static HTTP_REQUEST_DURATION_SECONDS: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "http_request_duration_seconds",
+        "Latency of the HTTP requests by route, method and status.",
+        &["route", "method", "status"]
+    )
+    .expect("The metric should only be registered once")
+});
+
+static CALCULATIONS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "calculations_total",
+        "Number of salary calculations by income type, currency and year.",
+        &["income_type", "currency", "year"]
+    )
+    .expect("The metric should only be registered once")
+});
+
+pub static DB_QUERY_DURATION_SECONDS: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "db_query_duration_seconds",
+        "Duration of the database queries.",
+        &["query"],
+        vec![0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5]
+    )
+    .expect("The metric should only be registered once")
+});
+
+static TAX_RATES_CACHE_REQUESTS_TOTAL: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "tax_rates_cache_requests_total",
+        "Lookups in the tax rates cache by result, hit or miss.",
+        &["result"]
+    )
+    .expect("The metric should only be registered once")
+});
+
+static TAX_RATES_CACHE_HIT_RATIO: LazyLock<Gauge> = LazyLock::new(|| {
+    register_gauge!(
+        "tax_rates_cache_hit_ratio",
+        "Share of the tax rates lookups served from the cache."
+    )
+    .expect("The metric should only be registered once")
+});
+
+// Middleware recording the count and latency of every request. The route is the matched
+// path template, so that ids in the path don't create a series per request.
+pub async fn track_metrics(request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| String::from("unmatched"));
+    let method = request.method().to_string();
+    let start = Instant::now();
+
+    let response = next.run(request).await;
+
+    let status = response.status().as_u16().to_string();
+    let labels = [route.as_str(), method.as_str(), status.as_str()];
+    HTTP_REQUESTS_TOTAL.with_label_values(&labels).inc();
+    HTTP_REQUEST_DURATION_SECONDS
+        .with_label_values(&labels)
+        .observe(start.elapsed().as_secs_f64());
+
+    response
+}
+
+pub fn record_calculation(income_type: &str, currency: &str, year: u32) {
+    CALCULATIONS_TOTAL
+        .with_label_values(&[income_type, currency, &year.to_string()])
+        .inc();
+}
+
+pub fn record_tax_rates_cache_lookup(hit: bool) {
+    let result = if hit { "hit" } else { "miss" };
+    TAX_RATES_CACHE_REQUESTS_TOTAL
+        .with_label_values(&[result])
+        .inc();
+
+    let hits = TAX_RATES_CACHE_REQUESTS_TOTAL
+        .with_label_values(&["hit"])
+        .get();
+    let misses = TAX_RATES_CACHE_REQUESTS_TOTAL
+        .with_label_values(&["miss"])
+        .get();
+    TAX_RATES_CACHE_HIT_RATIO.set(hits as f64 / (hits + misses) as f64);
+}
+
+// Renders every registered metric in the Prometheus text format.
+pub fn render_metrics() -> String {
+    // Registers the metrics so they are listed before being recorded for the first time.
+    LazyLock::force(&HTTP_REQUESTS_TOTAL);
+    LazyLock::force(&HTTP_REQUEST_DURATION_SECONDS);
+    LazyLock::force(&CALCULATIONS_TOTAL);
+    LazyLock::force(&DB_QUERY_DURATION_SECONDS);
+    LazyLock::force(&TAX_RATES_CACHE_REQUESTS_TOTAL);
+    LazyLock::force(&TAX_RATES_CACHE_HIT_RATIO);
+
+    let mut buffer = Vec::new();
+    TextEncoder::new()
+        .encode(&prometheus::gather(), &mut buffer)
+        .expect("The metrics should be encodable");
+    String::from_utf8(buffer).expect("The metrics should be valid UTF-8")
+}
@@ -0,0 +1,109 @@
+use axum::http::header::{ACCEPT_LANGUAGE, CONTENT_TYPE, LINK};
+use axum::http::{HeaderName, HeaderValue, Method};
+use axum::middleware::{from_fn, from_fn_with_state};
+use axum::Router;
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_http::compression::CompressionLayer;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_http::limit::RequestBodyLimitLayer;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+use tower_http::timeout::TimeoutLayer;
+use tower_http::trace::{DefaultOnResponse, TraceLayer};
+use tracing::Level;
+
+use crate::auth::API_KEY_HEADER;
+use crate::logging::make_request_span;
+use crate::metrics::track_metrics;
+use crate::rate_limit::{rate_limit, RateLimitConfig, RateLimiter};
+
+static DEFAULT_MAX_BODY_BYTES: usize = 64 * 1024;
+static DEFAULT_REQUEST_TIMEOUT_SECONDS: u64 = 10;
+
+#[derive(Debug)]
+pub struct MiddlewareConfig {
+    // Origins allowed to call the API from a browser, "*" allows any origin.
+    pub cors_allowed_origins: Vec<String>,
+    pub max_body_bytes: usize,
+    pub request_timeout: Duration,
+    pub rate_limits: RateLimitConfig,
+}
+
+impl MiddlewareConfig {
+    // Read from CORS_ALLOWED_ORIGINS (comma separated), MAX_BODY_BYTES,
+    // REQUEST_TIMEOUT_SECONDS and the rate limit variables.
+    pub fn from_env() -> Self {
+        MiddlewareConfig {
+            cors_allowed_origins: env::var("CORS_ALLOWED_ORIGINS")
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .map(String::from)
+                .collect(),
+            max_body_bytes: env::var("MAX_BODY_BYTES")
+                .ok()
+                .and_then(|bytes| bytes.parse().ok())
+                .unwrap_or(DEFAULT_MAX_BODY_BYTES),
+            request_timeout: Duration::from_secs(
+                env::var("REQUEST_TIMEOUT_SECONDS")
+                    .ok()
+                    .and_then(|seconds| seconds.parse().ok())
+                    .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECONDS),
+            ),
+            rate_limits: RateLimitConfig::from_env(),
+        }
+    }
+
+    fn cors_layer(&self) -> CorsLayer {
+        let allow_origin = if self.cors_allowed_origins.iter().any(|origin| origin == "*") {
+            AllowOrigin::any()
+        } else {
+            AllowOrigin::list(
+                self.cors_allowed_origins
+                    .iter()
+                    .filter_map(|origin| HeaderValue::from_str(origin).ok()),
+            )
+        };
+
+        CorsLayer::new()
+            .allow_origin(allow_origin)
+            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+            .allow_headers([
+                CONTENT_TYPE,
+                ACCEPT_LANGUAGE,
+                HeaderName::from_static("x-request-id"),
+                HeaderName::from_static(API_KEY_HEADER),
+            ])
+            .expose_headers([
+                HeaderName::from_static("x-request-id"),
+                HeaderName::from_static("deprecation"),
+                LINK,
+            ])
+    }
+}
+
+// Wraps the routes with the middleware stack. Layers run bottom to top, so a request goes
+// through the request id, tracing, CORS, compression, timeout, body limit and metrics layers
+// before reaching the routes. Only the API routes go through the rate limit layer, which
+// authenticates partners and counts their requests, the probes, metrics, docs and admin
+// routes are left out.
+pub fn apply_middleware(api_router: Router, router: Router, config: MiddlewareConfig) -> Router {
+    let cors_layer = config.cors_layer();
+    let limiter = Arc::new(RateLimiter::new(config.rate_limits));
+    router
+        .merge(api_router.layer(from_fn_with_state(limiter, rate_limit)))
+        .layer(from_fn(track_metrics))
+        .layer(RequestBodyLimitLayer::new(config.max_body_bytes))
+        .layer(TimeoutLayer::new(config.request_timeout))
+        .layer(CompressionLayer::new())
+        .layer(cors_layer)
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(make_request_span)
+                .on_response(DefaultOnResponse::new().level(Level::INFO)),
+        )
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+}
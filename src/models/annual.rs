@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+use crate::models::calculations::{CalculateSchema, CalculationInput, CalculationResults};
+use crate::utils::round_to;
+
+pub const MONTHS_PER_YEAR: usize = 12;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnualSchema {
+    pub year: Option<String>,
+    // The 12 months of the year in order, they take the year of the request and can't set one.
+    pub months: Option<Vec<CalculateSchema>>,
+}
+
+#[derive(Debug)]
+pub struct AnnualInput {
+    pub year: Option<u32>,
+    pub months: Vec<CalculationInput>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyPayslip {
+    // From 1 for January.
+    pub month: u32,
+    pub payslip: CalculationResults,
+}
+
+// The sums of the monthly payslips, as on the annual statement.
+#[derive(Debug, Default, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnualTotals {
+    pub brute_income: f64,
+    pub net_income: f64,
+    pub cas: f64,
+    pub cass: f64,
+    pub income_tax: f64,
+    pub cam: f64,
+    pub total_salary: f64,
+    pub benefits_value: f64,
+    pub take_home: f64,
+}
+
+impl AnnualTotals {
+    pub fn add(&mut self, payslip: &CalculationResults) {
+        self.brute_income += payslip.brute_income;
+        self.net_income += payslip.net_income;
+        self.cas += payslip.cas;
+        self.cass += payslip.cass;
+        self.income_tax += payslip.income_tax;
+        self.cam += payslip.cam;
+        self.total_salary += payslip.total_salary;
+        self.benefits_value += payslip.benefits_value;
+        self.take_home += payslip.take_home;
+    }
+
+    pub fn apply_rounding(&self, decimals: i32) -> Self {
+        AnnualTotals {
+            brute_income: round_to(self.brute_income, decimals),
+            net_income: round_to(self.net_income, decimals),
+            cas: round_to(self.cas, decimals),
+            cass: round_to(self.cass, decimals),
+            income_tax: round_to(self.income_tax, decimals),
+            cam: round_to(self.cam, decimals),
+            total_salary: round_to(self.total_salary, decimals),
+            benefits_value: round_to(self.benefits_value, decimals),
+            take_home: round_to(self.take_home, decimals),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnualResults {
+    pub year: u32,
+    pub months: Vec<MonthlyPayslip>,
+    pub totals: AnnualTotals,
+    pub labels: BTreeMap<&'static str, &'static str>,
+}
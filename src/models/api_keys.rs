@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::database::api_keys::ApiKeyUsage;
+
+// Requests allowed per day and per minute when the key is issued without limits.
+pub const DEFAULT_DAILY_QUOTA: u32 = 10_000;
+pub const DEFAULT_REQUESTS_PER_MINUTE: u32 = 600;
+
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeySchema {
+    #[schema(example = "Job board")]
+    pub name: Option<String>,
+    #[schema(example = "10000")]
+    pub daily_quota: Option<String>,
+    #[schema(example = "600")]
+    pub requests_per_minute: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct ApiKeyInput {
+    pub name: String,
+    pub daily_quota: u32,
+    pub requests_per_minute: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyInfo {
+    pub id: i64,
+    pub name: String,
+    // The first characters of the key, to recognise it without storing it.
+    pub prefix: String,
+    pub daily_quota: u32,
+    pub requests_per_minute: u32,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+    pub requests_today: u32,
+    pub requests_total: u32,
+}
+
+impl From<ApiKeyUsage> for ApiKeyInfo {
+    fn from(usage: ApiKeyUsage) -> Self {
+        ApiKeyInfo {
+            id: usage.api_key.id,
+            name: usage.api_key.name,
+            prefix: usage.api_key.prefix,
+            daily_quota: usage.api_key.daily_quota,
+            requests_per_minute: usage.api_key.requests_per_minute,
+            created_at: usage.api_key.created_at,
+            revoked_at: usage.api_key.revoked_at,
+            requests_today: usage.requests_today,
+            requests_total: usage.requests_total,
+        }
+    }
+}
+
+// Returned once when a key is issued, the key cannot be read again afterwards.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuedApiKey {
+    pub key: String,
+    pub api_key: ApiKeyInfo,
+}
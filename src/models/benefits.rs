@@ -0,0 +1,93 @@
+use crate::models::calculations::NumberOrString;
+use crate::utils::round_to;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BenefitType {
+    MealVoucher,
+    HolidayVoucher,
+    Other,
+}
+
+impl BenefitType {
+    pub(crate) fn from_str(benefit_type: &str) -> Option<BenefitType> {
+        match benefit_type.trim().to_uppercase().as_str() {
+            "MEAL_VOUCHER" => Some(BenefitType::MealVoucher),
+            "HOLIDAY_VOUCHER" => Some(BenefitType::HolidayVoucher),
+            "OTHER" => Some(BenefitType::Other),
+            _ => None,
+        }
+    }
+
+    // The key used for the benefit in the database and in the responses.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BenefitType::MealVoucher => "MEAL_VOUCHER",
+            BenefitType::HolidayVoucher => "HOLIDAY_VOUCHER",
+            BenefitType::Other => "OTHER",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BenefitSchema {
+    #[serde(rename = "type")]
+    pub benefit_type: Option<String>,
+    pub amount: Option<NumberOrString>,
+    pub count: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Benefit {
+    pub benefit_type: BenefitType,
+    pub amount: f64,
+    pub count: u32,
+}
+
+impl Benefit {
+    pub fn value(&self) -> f64 {
+        self.amount * self.count as f64
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BenefitResult {
+    pub benefit_type: &'static str,
+    pub value: f64,
+    pub cas: f64,
+    pub cass: f64,
+    pub income_tax: f64,
+    pub cam: f64,
+}
+
+impl BenefitResult {
+    // Contributions and taxes withheld from the employee's cash salary.
+    pub fn employee_deductions(&self) -> f64 {
+        self.cas + self.cass + self.income_tax
+    }
+
+    pub fn apply_rounding(&self, decimals: i32) -> Self {
+        BenefitResult {
+            benefit_type: self.benefit_type,
+            value: round_to(self.value, decimals),
+            cas: round_to(self.cas, decimals),
+            cass: round_to(self.cass, decimals),
+            income_tax: round_to(self.income_tax, decimals),
+            cam: round_to(self.cam, decimals),
+        }
+    }
+
+    pub fn restate(&self, factor: f64) -> Self {
+        BenefitResult {
+            benefit_type: self.benefit_type,
+            value: self.value * factor,
+            cas: self.cas * factor,
+            cass: self.cass * factor,
+            income_tax: self.income_tax * factor,
+            cam: self.cam * factor,
+        }
+    }
+}
@@ -1,7 +1,16 @@
+use crate::database::db::{BenefitRule, IncomeTaxExemption, PfaThresholds, TaxRates};
+use crate::models::benefits::{Benefit, BenefitResult, BenefitSchema};
+use crate::models::payslip::{
+    IncomeComponentResult, Overtime, OvertimeSchema, Payment, PaymentSchema,
+};
 use crate::utils::round_to;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
 
 #[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum Currency {
     DOLLAR,
     RON,
@@ -20,9 +29,14 @@ impl Currency {
 }
 
 #[derive(Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms, non_camel_case_types)]
 pub enum IncomeType {
     NET,
     BRUTE,
+    // The full budget of the employer, the brute salary plus CAM and the benefits.
+    TOTAL_COST,
+    // Self-employed (persoană fizică autorizată), the income and expenses are yearly.
+    PFA,
 }
 
 impl IncomeType {
@@ -30,34 +44,68 @@ impl IncomeType {
         match income_type.trim().to_uppercase().as_str() {
             "NET" => Some(IncomeType::NET),
             "BRUTE" => Some(IncomeType::BRUTE),
+            "TOTAL_COST" => Some(IncomeType::TOTAL_COST),
+            "PFA" => Some(IncomeType::PFA),
             _ => None,
         }
     }
 }
 
-#[derive(Debug, Deserialize)]
+// Amounts can be sent either as JSON numbers or as strings using local separators.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum NumberOrString {
+    Number(f64),
+    String(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CalculateSchema {
     // We have all parameters optional because we want to output
     // custom error messages from validations instead of a typical
     // axum failure message when a parameter is missing.
-    pub income: Option<String>,
+    pub income: Option<NumberOrString>,
     pub income_type: Option<String>,
     pub currency: Option<String>,
     pub custom_tax: Option<String>,
     pub year: Option<String>,
+    pub benefits: Option<Vec<BenefitSchema>>,
+    pub bonuses: Option<Vec<PaymentSchema>>,
+    pub overtime: Option<Vec<OvertimeSchema>>,
+    pub one_off_payments: Option<Vec<PaymentSchema>>,
+    pub working_hours: Option<String>,
+    // Yearly deductible expenses, only for the PFA income type.
+    pub expenses: Option<NumberOrString>,
+    // The employee attributes the income tax exemptions depend on, not for the PFA income type.
+    #[schema(example = "2001-05-20")]
+    pub birth_date: Option<String>,
+    pub disability: Option<bool>,
 }
 
 #[derive(Debug)]
 pub struct CalculationInput {
-    pub income: u32,
+    pub income: f64,
     pub income_type: IncomeType,
+    // Not used by the calculations yet, only reported in the metrics.
     pub currency: Currency,
     pub year: Option<u32>,
+    // Set by the annual calculation, so that the rates changing during the year apply.
+    pub month: Option<u32>,
+    #[allow(dead_code)]
     pub custom_tax: Option<u32>,
+    pub benefits: Vec<Benefit>,
+    pub bonuses: Vec<Payment>,
+    pub overtime: Vec<Overtime>,
+    pub one_off_payments: Vec<Payment>,
+    pub working_hours: f64,
+    pub expenses: f64,
+    pub birth_date: Option<NaiveDate>,
+    pub disability: bool,
 }
 
-#[derive(Debug, Serialize)]
+// Kept for the deprecated unversioned `/taxes` route.
+#[derive(Debug, Serialize, ToSchema)]
 pub struct TaxInfo<'a> {
     pub year: &'a i32,
     pub cas: &'a f64,
@@ -67,7 +115,44 @@ pub struct TaxInfo<'a> {
     pub dp: Option<&'a f64>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaxRatesInfo {
+    pub year: i32,
+    pub cas: f64,
+    pub cass: f64,
+    pub income_tax: f64,
+    pub cam: f64,
+}
+
+impl From<&TaxRates> for TaxRatesInfo {
+    fn from(tax_rates: &TaxRates) -> Self {
+        TaxRatesInfo {
+            year: tax_rates.year,
+            cas: tax_rates.social_security,
+            cass: tax_rates.health_insurance,
+            income_tax: tax_rates.income_tax,
+            cam: tax_rates.insurance_contribution,
+        }
+    }
+}
+
+// The rates and rules a calculation was made with, saved with a shared calculation so that
+// its breakdown can still be explained once they change.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AppliedRules {
+    // The rates in force in the calculated month, or the yearly rates without a month.
+    pub tax_rates: TaxRatesInfo,
+    // Only for the self-employed.
+    pub pfa_thresholds: Option<PfaThresholds>,
+    pub benefit_rules: Vec<BenefitRule>,
+    // The exemption from the income tax the employee got.
+    pub exemption: Option<IncomeTaxExemption>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CalculationResults {
     pub brute_income: f64,
     pub net_income: f64,
@@ -78,6 +163,14 @@ pub struct CalculationResults {
     pub total_salary: f64,
     pub employee_tax_percentage: f64,
     pub state_tax_percentage: f64,
+    pub benefits: Vec<BenefitResult>,
+    pub benefits_value: f64,
+    pub take_home: f64,
+    pub expenses: f64,
+    // The income tax exemption applied, such as "UNDER_26" or "DISABILITY".
+    pub exemption: Option<String>,
+    pub income_components: Vec<IncomeComponentResult>,
+    pub labels: BTreeMap<&'static str, &'static str>,
 }
 
 impl Default for CalculationResults {
@@ -92,6 +185,13 @@ impl Default for CalculationResults {
             total_salary: 0.0,
             employee_tax_percentage: 0.0,
             state_tax_percentage: 0.0,
+            benefits: Vec::new(),
+            benefits_value: 0.0,
+            take_home: 0.0,
+            expenses: 0.0,
+            exemption: None,
+            income_components: Vec::new(),
+            labels: BTreeMap::new(),
         }
     }
 }
@@ -108,6 +208,51 @@ impl CalculationResults {
             total_salary: round_to(self.total_salary, decimals),
             employee_tax_percentage: round_to(self.employee_tax_percentage, decimals),
             state_tax_percentage: round_to(self.state_tax_percentage, decimals),
+            benefits: self
+                .benefits
+                .iter()
+                .map(|benefit| benefit.apply_rounding(decimals))
+                .collect(),
+            benefits_value: round_to(self.benefits_value, decimals),
+            take_home: round_to(self.take_home, decimals),
+            expenses: round_to(self.expenses, decimals),
+            exemption: self.exemption.clone(),
+            income_components: self
+                .income_components
+                .iter()
+                .map(|component| component.apply_rounding(decimals))
+                .collect(),
+            labels: self.labels.clone(),
+        }
+    }
+
+    // The same breakdown in the money of another year, every amount multiplied by the factor.
+    pub fn restate(&self, factor: f64) -> Self {
+        CalculationResults {
+            brute_income: self.brute_income * factor,
+            net_income: self.net_income * factor,
+            cas: self.cas * factor,
+            cass: self.cass * factor,
+            income_tax: self.income_tax * factor,
+            cam: self.cam * factor,
+            total_salary: self.total_salary * factor,
+            employee_tax_percentage: self.employee_tax_percentage,
+            state_tax_percentage: self.state_tax_percentage,
+            benefits: self
+                .benefits
+                .iter()
+                .map(|benefit| benefit.restate(factor))
+                .collect(),
+            benefits_value: self.benefits_value * factor,
+            take_home: self.take_home * factor,
+            expenses: self.expenses * factor,
+            exemption: self.exemption.clone(),
+            income_components: self
+                .income_components
+                .iter()
+                .map(|component| component.restate(factor))
+                .collect(),
+            labels: self.labels.clone(),
         }
     }
 }
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::calculations::NumberOrString;
+use crate::utils::round_to;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompareSchema {
+    // The yearly employer cost of a job, or the yearly revenue of a PFA or a company.
+    pub budget: Option<NumberOrString>,
+    // Yearly deductible expenses of the PFA and the company.
+    pub expenses: Option<NumberOrString>,
+    pub year: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct CompareInput {
+    pub budget: f64,
+    pub expenses: f64,
+    pub year: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, ToSchema)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Regime {
+    // Employment contract (contract individual de muncă).
+    CIM,
+    PFA,
+    MICRO,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RegimeOutcome {
+    pub regime: Regime,
+    pub net_income: f64,
+    pub total_taxes: f64,
+    // The total taxes as a share of the budget minus the expenses.
+    pub effective_tax_rate: f64,
+}
+
+impl RegimeOutcome {
+    pub fn apply_rounding(&self, decimals: i32) -> Self {
+        RegimeOutcome {
+            regime: self.regime,
+            net_income: round_to(self.net_income, decimals),
+            total_taxes: round_to(self.total_taxes, decimals),
+            effective_tax_rate: round_to(self.effective_tax_rate, decimals),
+        }
+    }
+}
+
+// The yearly outcomes of every regime for the same budget, best is the highest net income.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CompareResults {
+    pub budget: f64,
+    pub expenses: f64,
+    pub year: u32,
+    pub outcomes: Vec<RegimeOutcome>,
+    pub best: Regime,
+}
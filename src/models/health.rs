@@ -0,0 +1,41 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ComponentStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentReport {
+    pub status: ComponentStatus,
+    pub message: String,
+}
+
+impl ComponentReport {
+    pub fn up(message: String) -> Self {
+        ComponentReport {
+            status: ComponentStatus::Up,
+            message,
+        }
+    }
+
+    pub fn down(message: String) -> Self {
+        ComponentReport {
+            status: ComponentStatus::Down,
+            message,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadinessReport {
+    pub ready: bool,
+    pub database: ComponentReport,
+    pub schema: ComponentReport,
+    pub tax_rates: ComponentReport,
+}
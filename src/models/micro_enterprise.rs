@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::calculations::NumberOrString;
+use crate::utils::round_to;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MicroEnterpriseSchema {
+    // Yearly amounts of the company.
+    pub revenue: Option<NumberOrString>,
+    pub expenses: Option<NumberOrString>,
+    pub year: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct MicroEnterpriseInput {
+    pub revenue: f64,
+    pub expenses: f64,
+    pub year: Option<u32>,
+}
+
+// The yearly outcome of a micro-enterprise paying its associate the minimum wage and the
+// remaining profit as dividends.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MicroEnterpriseResults {
+    pub revenue: f64,
+    pub expenses: f64,
+    pub turnover_tax: f64,
+    pub gross_salary: f64,
+    // CAS, CASS and income tax withheld from the salary.
+    pub salary_taxes: f64,
+    pub cam: f64,
+    // The gross salary and CAM paid by the company.
+    pub salary_costs: f64,
+    pub net_salary: f64,
+    pub distributable_dividend: f64,
+    pub dividend_tax: f64,
+    // CASS due by the associate on the dividends.
+    pub cass: f64,
+    pub net_dividend: f64,
+    pub take_home: f64,
+    pub total_taxes: f64,
+    // The total taxes as a share of the revenue minus the expenses.
+    pub effective_tax_rate: f64,
+}
+
+impl MicroEnterpriseResults {
+    pub fn apply_rounding(&self, decimals: i32) -> Self {
+        MicroEnterpriseResults {
+            revenue: round_to(self.revenue, decimals),
+            expenses: round_to(self.expenses, decimals),
+            turnover_tax: round_to(self.turnover_tax, decimals),
+            gross_salary: round_to(self.gross_salary, decimals),
+            salary_taxes: round_to(self.salary_taxes, decimals),
+            cam: round_to(self.cam, decimals),
+            salary_costs: round_to(self.salary_costs, decimals),
+            net_salary: round_to(self.net_salary, decimals),
+            distributable_dividend: round_to(self.distributable_dividend, decimals),
+            dividend_tax: round_to(self.dividend_tax, decimals),
+            cass: round_to(self.cass, decimals),
+            net_dividend: round_to(self.net_dividend, decimals),
+            take_home: round_to(self.take_home, decimals),
+            total_taxes: round_to(self.total_taxes, decimals),
+            effective_tax_rate: round_to(self.effective_tax_rate, decimals),
+        }
+    }
+}
@@ -1 +1,13 @@
+pub mod annual;
+pub mod api_keys;
+pub mod benefits;
 pub mod calculations;
+pub mod compare;
+pub mod health;
+pub mod micro_enterprise;
+pub mod other_income;
+pub mod payroll;
+pub mod payslip;
+pub mod real_income;
+pub mod shared_calculations;
+pub mod validation;
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::calculations::NumberOrString;
+use crate::utils::round_to;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OtherIncomeCategory {
+    Rental,
+    Investment,
+    Dividends,
+}
+
+impl OtherIncomeCategory {
+    pub(crate) fn from_str(category: &str) -> Option<OtherIncomeCategory> {
+        match category.trim().to_uppercase().as_str() {
+            "RENTAL" => Some(OtherIncomeCategory::Rental),
+            "INVESTMENT" => Some(OtherIncomeCategory::Investment),
+            "DIVIDENDS" => Some(OtherIncomeCategory::Dividends),
+            _ => None,
+        }
+    }
+
+    // The key used for the category in the database and in the responses.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OtherIncomeCategory::Rental => "RENTAL",
+            OtherIncomeCategory::Investment => "INVESTMENT",
+            OtherIncomeCategory::Dividends => "DIVIDENDS",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OtherIncomeSourceSchema {
+    #[schema(example = "RENTAL")]
+    pub category: Option<String>,
+    // The yearly gross amount.
+    pub amount: Option<NumberOrString>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OtherIncomeSchema {
+    pub incomes: Option<Vec<OtherIncomeSourceSchema>>,
+    pub year: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct OtherIncomeSource {
+    pub category: OtherIncomeCategory,
+    pub amount: f64,
+}
+
+#[derive(Debug)]
+pub struct OtherIncomeInput {
+    pub incomes: Vec<OtherIncomeSource>,
+    pub year: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OtherIncomeCategoryResult {
+    pub category: &'static str,
+    pub amount: f64,
+    // The amount minus the flat deduction of the category.
+    pub taxable_income: f64,
+    pub income_tax: f64,
+    pub withheld_at_source: bool,
+}
+
+impl OtherIncomeCategoryResult {
+    pub fn apply_rounding(&self, decimals: i32) -> Self {
+        OtherIncomeCategoryResult {
+            category: self.category,
+            amount: round_to(self.amount, decimals),
+            taxable_income: round_to(self.taxable_income, decimals),
+            income_tax: round_to(self.income_tax, decimals),
+            withheld_at_source: self.withheld_at_source,
+        }
+    }
+}
+
+// The yearly taxes on the income earned outside a salary, as declared on the Declarația unică.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OtherIncomeResults {
+    pub year: u32,
+    pub categories: Vec<OtherIncomeCategoryResult>,
+    pub taxable_income: f64,
+    // CASS is due on a fixed base depending on the bracket reached by the taxable income.
+    pub cass_base: f64,
+    pub cass: f64,
+    pub income_tax: f64,
+    // The income tax already withheld by the payers, such as the dividend tax.
+    pub withheld_tax: f64,
+    // The CASS and the income tax not withheld, owed on the tax return.
+    pub declaration_total: f64,
+}
+
+impl OtherIncomeResults {
+    pub fn apply_rounding(&self, decimals: i32) -> Self {
+        OtherIncomeResults {
+            year: self.year,
+            categories: self
+                .categories
+                .iter()
+                .map(|category| category.apply_rounding(decimals))
+                .collect(),
+            taxable_income: round_to(self.taxable_income, decimals),
+            cass_base: round_to(self.cass_base, decimals),
+            cass: round_to(self.cass, decimals),
+            income_tax: round_to(self.income_tax, decimals),
+            withheld_tax: round_to(self.withheld_tax, decimals),
+            declaration_total: round_to(self.declaration_total, decimals),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+use crate::models::calculations::{
+    CalculateSchema, CalculationInput, CalculationResults, NumberOrString,
+};
+use crate::utils::round_to;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeeSchema {
+    #[schema(example = "Ana Popescu")]
+    pub name: Option<String>,
+    // The personal numeric code, required on the D112 return.
+    #[schema(example = "2900101123459")]
+    pub cnp: Option<String>,
+    // The activity sector of the employee, the totals are also given per sector.
+    #[schema(example = "IT")]
+    pub sector: Option<String>,
+    // Amounts withheld from the net salary, such as advances or garnishments, at most the
+    // net salary is withheld.
+    pub deductions: Option<NumberOrString>,
+    // Rejected, the year and month are the ones of the payroll.
+    pub month: Option<String>,
+    // The salary of the employee, as for a single calculation.
+    #[serde(flatten)]
+    pub salary: CalculateSchema,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployerSchema {
+    // The fiscal code, without the RO prefix.
+    #[schema(example = "14841555")]
+    pub cif: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PayrollSchema {
+    // Only needed for the D112 export.
+    pub employer: Option<EmployerSchema>,
+    pub year: Option<String>,
+    // From 1 for January, the rates in force that month are used.
+    pub month: Option<String>,
+    pub employees: Option<Vec<EmployeeSchema>>,
+}
+
+#[derive(Debug)]
+pub struct EmployeeInput {
+    pub name: String,
+    pub cnp: Option<String>,
+    pub sector: Option<String>,
+    pub deductions: f64,
+    pub salary: CalculationInput,
+}
+
+#[derive(Debug)]
+pub struct PayrollInput {
+    pub year: Option<u32>,
+    pub month: Option<u32>,
+    pub employees: Vec<EmployeeInput>,
+}
+
+#[derive(Debug)]
+pub struct Employer {
+    pub cif: String,
+    pub name: String,
+}
+
+// A payroll declared on the D112 return, which is always monthly.
+#[derive(Debug)]
+pub struct D112Input {
+    pub employer: Employer,
+    pub month: u32,
+    pub payroll: PayrollInput,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EmployeePayslip {
+    pub name: String,
+    pub cnp: Option<String>,
+    pub sector: Option<String>,
+    pub payslip: CalculationResults,
+    // The deductions withheld, capped at the net income.
+    pub deductions: f64,
+    // The net income minus the deductions, paid to the employee.
+    pub paid_net: f64,
+}
+
+// The sums of the payslips, the contributions are the amounts declared on the D112 return.
+#[derive(Debug, Default, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PayrollTotals {
+    pub employees: u32,
+    pub brute_income: f64,
+    pub net_income: f64,
+    pub deductions: f64,
+    pub paid_net: f64,
+    pub cas: f64,
+    pub cass: f64,
+    pub income_tax: f64,
+    pub cam: f64,
+    // CAS, CASS, income tax and CAM paid to the state by the employer.
+    pub contributions: f64,
+    pub total_salary: f64,
+}
+
+impl PayrollTotals {
+    pub fn add(&mut self, employee: &EmployeePayslip) {
+        let payslip = &employee.payslip;
+        self.employees += 1;
+        self.brute_income += payslip.brute_income;
+        self.net_income += payslip.net_income;
+        self.deductions += employee.deductions;
+        self.paid_net += employee.paid_net;
+        self.cas += payslip.cas;
+        self.cass += payslip.cass;
+        self.income_tax += payslip.income_tax;
+        self.cam += payslip.cam;
+        self.contributions += payslip.cas + payslip.cass + payslip.income_tax + payslip.cam;
+        self.total_salary += payslip.total_salary;
+    }
+
+    pub fn apply_rounding(&self, decimals: i32) -> Self {
+        PayrollTotals {
+            employees: self.employees,
+            brute_income: round_to(self.brute_income, decimals),
+            net_income: round_to(self.net_income, decimals),
+            deductions: round_to(self.deductions, decimals),
+            paid_net: round_to(self.paid_net, decimals),
+            cas: round_to(self.cas, decimals),
+            cass: round_to(self.cass, decimals),
+            income_tax: round_to(self.income_tax, decimals),
+            cam: round_to(self.cam, decimals),
+            contributions: round_to(self.contributions, decimals),
+            total_salary: round_to(self.total_salary, decimals),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PayrollResults {
+    pub year: u32,
+    pub month: Option<u32>,
+    pub employees: Vec<EmployeePayslip>,
+    pub totals: PayrollTotals,
+    // Keyed by sector, employees without a sector are left out.
+    pub sectors: BTreeMap<String, PayrollTotals>,
+    pub labels: BTreeMap<&'static str, &'static str>,
+}
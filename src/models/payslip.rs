@@ -0,0 +1,95 @@
+use crate::models::calculations::NumberOrString;
+use crate::utils::round_to;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+// The monthly working hours used to derive the hourly rate for overtime.
+pub const DEFAULT_WORKING_HOURS: f64 = 168.0;
+// The Labour Code asks for at least 75% on top of the hourly rate for overtime.
+pub const DEFAULT_OVERTIME_MULTIPLIER: f64 = 1.75;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IncomeComponentType {
+    BaseSalary,
+    Bonus,
+    Overtime,
+    OneOffPayment,
+}
+
+impl IncomeComponentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IncomeComponentType::BaseSalary => "BASE_SALARY",
+            IncomeComponentType::Bonus => "BONUS",
+            IncomeComponentType::Overtime => "OVERTIME",
+            IncomeComponentType::OneOffPayment => "ONE_OFF_PAYMENT",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentSchema {
+    pub name: Option<String>,
+    pub amount: Option<NumberOrString>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OvertimeSchema {
+    pub hours: Option<String>,
+    pub multiplier: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Payment {
+    pub name: Option<String>,
+    pub amount: f64,
+}
+
+#[derive(Debug)]
+pub struct Overtime {
+    pub hours: f64,
+    pub multiplier: f64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeComponentResult {
+    pub component_type: &'static str,
+    pub name: Option<String>,
+    pub brute_income: f64,
+    pub cas: f64,
+    pub cass: f64,
+    pub income_tax: f64,
+    pub net_income: f64,
+    pub cam: f64,
+}
+
+impl IncomeComponentResult {
+    pub fn apply_rounding(&self, decimals: i32) -> Self {
+        IncomeComponentResult {
+            component_type: self.component_type,
+            name: self.name.clone(),
+            brute_income: round_to(self.brute_income, decimals),
+            cas: round_to(self.cas, decimals),
+            cass: round_to(self.cass, decimals),
+            income_tax: round_to(self.income_tax, decimals),
+            net_income: round_to(self.net_income, decimals),
+            cam: round_to(self.cam, decimals),
+        }
+    }
+
+    pub fn restate(&self, factor: f64) -> Self {
+        IncomeComponentResult {
+            component_type: self.component_type,
+            name: self.name.clone(),
+            brute_income: self.brute_income * factor,
+            cas: self.cas * factor,
+            cass: self.cass * factor,
+            income_tax: self.income_tax * factor,
+            net_income: self.net_income * factor,
+            cam: self.cam * factor,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+use crate::models::calculations::{CalculateSchema, CalculationInput, CalculationResults};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RealIncomeSchema {
+    // Two salaries, each with its own year, as for a single calculation. The year is required.
+    pub from: Option<CalculateSchema>,
+    pub to: Option<CalculateSchema>,
+}
+
+#[derive(Debug)]
+pub struct RealIncomeInput {
+    pub from: CalculationInput,
+    pub to: CalculationInput,
+}
+
+// The salary of the first year restated in the money of the second one, compared to the
+// salary of the second year.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RealIncomeResults {
+    pub from_year: u32,
+    pub to_year: u32,
+    // The ratio of the consumer price indices of the two years.
+    pub inflation_factor: f64,
+    // The ratio of the average gross wages of the two years.
+    pub wage_factor: f64,
+    pub from: CalculationResults,
+    // The first salary multiplied by the inflation factor.
+    pub restated: CalculationResults,
+    pub to: CalculationResults,
+    // The net income of the second year minus the restated one, positive when it buys more.
+    pub real_net_change: f64,
+    pub real_net_change_percentage: f64,
+    // The net income of the first year had it followed the average wage.
+    pub wage_indexed_net_income: f64,
+    pub labels: BTreeMap<&'static str, &'static str>,
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::database::shared_calculations::StoredCalculation;
+
+// A calculation saved to be shared through a link. The input, the rates and rules applied and
+// the results are returned as they were stored, so the breakdown stays the same after the
+// rates change.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedCalculation {
+    #[schema(example = "k3Xb9QzT2m")]
+    pub id: String,
+    pub created_at: String,
+    pub expires_at: String,
+    #[schema(value_type = CalculateSchema)]
+    pub input: Value,
+    #[schema(value_type = AppliedRules)]
+    pub rules: Value,
+    #[schema(value_type = CalculationResults)]
+    pub results: Value,
+}
+
+impl TryFrom<StoredCalculation> for SharedCalculation {
+    type Error = serde_json::Error;
+
+    fn try_from(stored: StoredCalculation) -> Result<Self, Self::Error> {
+        Ok(SharedCalculation {
+            id: stored.id,
+            created_at: stored.created_at,
+            expires_at: stored.expires_at,
+            input: serde_json::from_str(&stored.input)?,
+            rules: serde_json::from_str(&stored.rules)?,
+            results: serde_json::from_str(&stored.results)?,
+        })
+    }
+}
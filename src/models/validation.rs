@@ -0,0 +1,66 @@
+use crate::messages::{render_message, Language};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ValidationError {
+    pub field: String,
+    pub code: &'static str,
+    pub message: String,
+    // Kept to render the message again in the requested language.
+    #[serde(skip)]
+    pub label: &'static str,
+    #[serde(skip)]
+    pub value: Option<String>,
+}
+
+impl ValidationError {
+    pub fn new(
+        field: impl Into<String>,
+        code: &'static str,
+        label: &'static str,
+        value: Option<String>,
+    ) -> Self {
+        ValidationError {
+            field: field.into(),
+            code,
+            message: render_message(Language::English, code, label, value.as_deref()),
+            label,
+            value,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct ValidationReport {
+    pub errors: Vec<ValidationError>,
+}
+
+impl ValidationReport {
+    pub fn push(&mut self, error: ValidationError) {
+        self.errors.push(error);
+    }
+
+    // Returns the value when valid, otherwise records the error.
+    pub fn check<T>(&mut self, result: Result<T, ValidationError>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn localize(mut self, language: Language) -> Self {
+        for error in self.errors.iter_mut() {
+            error.message =
+                render_message(language, error.code, error.label, error.value.as_deref());
+        }
+        self
+    }
+}
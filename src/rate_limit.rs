@@ -0,0 +1,239 @@
+use axum::extract::{ConnectInfo, MatchedPath, Request, State};
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderMap, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use chrono::{Days, Utc};
+use rusqlite::Connection;
+use std::collections::HashMap;
+use std::env;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::auth::Client;
+use crate::database::api_keys::record_api_key_usage;
+use crate::messages::{render_message, Language};
+
+// Requests per minute allowed for each anonymous client on the calculation routes, the other
+// routes are not limited unless configured. Partners get the limit of their API key instead.
+static DEFAULT_RATE_LIMITS: &str = concat!(
+    "/v1/calculate=120,/calculate=120,/v1/calculate/annual=30,/v1/calculations=30,",
+    "/v1/micro-enterprise=120,/v1/other-income=120,/v1/compare=60,/v1/payroll=30,",
+    "/v1/payroll/d112=30,/v1/real-income=60"
+);
+
+// Buckets unused for this long are full again, so they are dropped.
+static IDLE_BUCKET_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub requests_per_minute: u32,
+}
+
+impl RateLimit {
+    fn refill_per_second(&self) -> f64 {
+        self.requests_per_minute as f64 / 60.0
+    }
+}
+
+#[derive(Debug)]
+pub struct RateLimitConfig {
+    // Keyed by route template, such as "/v1/calculate".
+    pub routes: HashMap<String, RateLimit>,
+    // Header holding the client address set by a trusted reverse proxy, such as
+    // "x-forwarded-for". The socket address is used when unset.
+    pub trusted_proxy_header: Option<String>,
+    // Number of trusted proxies in front of the server, each appends the address it got the
+    // request from so the client is this many entries from the right of the header.
+    pub trusted_proxy_hops: usize,
+}
+
+impl RateLimitConfig {
+    // Read from RATE_LIMITS ("route=requests per minute", comma separated, "none" disables
+    // the limits), TRUSTED_PROXY_HEADER and TRUSTED_PROXY_HOPS.
+    pub fn from_env() -> Self {
+        let limits = env::var("RATE_LIMITS").unwrap_or_else(|_| DEFAULT_RATE_LIMITS.to_string());
+        RateLimitConfig {
+            routes: parse_rate_limits(&limits),
+            trusted_proxy_header: env::var("TRUSTED_PROXY_HEADER")
+                .ok()
+                .map(|header| header.trim().to_lowercase())
+                .filter(|header| !header.is_empty()),
+            trusted_proxy_hops: env::var("TRUSTED_PROXY_HOPS")
+                .ok()
+                .and_then(|hops| hops.trim().parse().ok())
+                .filter(|hops| *hops > 0)
+                .unwrap_or(1),
+        }
+    }
+}
+
+fn parse_rate_limits(limits: &str) -> HashMap<String, RateLimit> {
+    limits
+        .split(',')
+        .filter_map(|limit| limit.split_once('='))
+        .filter_map(|(route, requests_per_minute)| {
+            let requests_per_minute = requests_per_minute.trim().parse().ok()?;
+            if requests_per_minute == 0 {
+                tracing::warn!(route, "Ignoring a rate limit of 0 requests per minute");
+                return None;
+            }
+            Some((
+                route.trim().to_string(),
+                RateLimit {
+                    requests_per_minute,
+                },
+            ))
+        })
+        .collect()
+}
+
+// Anonymous clients are told apart by their address, partners by their API key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ClientId {
+    Address(IpAddr),
+    ApiKey(i64),
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+// Token buckets kept in memory per route and client, each holds up to a minute of requests
+// and refills continuously.
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: Mutex<HashMap<(String, ClientId), Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        RateLimiter {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Takes a token for the client, or returns how long until one is available.
+    fn acquire(&self, route: &str, client: ClientId, limit: RateLimit) -> Result<(), Duration> {
+        let now = Instant::now();
+        let capacity = limit.requests_per_minute as f64;
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() > 10_000 {
+            buckets.retain(|_, bucket| now.duration_since(bucket.updated_at) < IDLE_BUCKET_TIMEOUT);
+        }
+
+        let bucket = buckets
+            .entry((route.to_string(), client))
+            .or_insert(Bucket {
+                tokens: capacity,
+                updated_at: now,
+            });
+        let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * limit.refill_per_second()).min(capacity);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / limit.refill_per_second(),
+            ))
+        }
+    }
+
+    // The address the outermost trusted proxy got the request from, falling back to the
+    // socket address. The entries left of it are sent by the client and cannot be trusted.
+    fn client_ip(&self, headers: &HeaderMap, socket: Option<SocketAddr>) -> Option<IpAddr> {
+        self.config
+            .trusted_proxy_header
+            .as_ref()
+            .and_then(|header| headers.get(header.as_str()))
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.rsplit(',').nth(self.config.trusted_proxy_hops - 1))
+            .and_then(|address| address.trim().parse().ok())
+            .or_else(|| socket.map(|socket| socket.ip()))
+    }
+}
+
+fn too_many_requests(language: Language, code: &str, retry_after: Duration) -> Response {
+    let retry_after = retry_after.as_secs_f64().ceil().max(1.0).to_string();
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        [(RETRY_AFTER, retry_after.clone())],
+        render_message(language, code, "", Some(&retry_after)),
+    )
+        .into_response()
+}
+
+// The daily quotas reset at midnight UTC.
+fn until_next_day() -> Duration {
+    let now = Utc::now();
+    let tomorrow = now.date_naive() + Days::new(1);
+    (tomorrow.and_hms_opt(0, 0, 0).unwrap().and_utc() - now)
+        .to_std()
+        .unwrap_or_default()
+}
+
+// Middleware answering 429 with a Retry-After header once a client runs out of tokens on a
+// limited route. Every request admitted with an API key is also counted against its daily
+// quota.
+pub async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    language: Language,
+    client: Client,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(retry_after) = acquire_token(&limiter, &client, &request) {
+        return too_many_requests(language, "rate_limited", retry_after);
+    }
+
+    if let Client::Partner(ref api_key) = client {
+        let today = Utc::now().date_naive().to_string();
+        match Connection::open("./tax_rates.db")
+            .and_then(|conn| record_api_key_usage(&conn, api_key, &today))
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                tracing::info!(api_key = api_key.id, "API key quota exceeded");
+                return too_many_requests(language, "api_key_quota_exceeded", until_next_day());
+            }
+            Err(error) => tracing::warn!(%error, "Could not record the API key usage"),
+        }
+    }
+
+    next.run(request).await
+}
+
+// Takes a token from the bucket of the client on the route, giving the time to wait when the
+// bucket is empty. Routes without a limit and clients without an address are not limited.
+fn acquire_token(limiter: &RateLimiter, client: &Client, request: &Request) -> Option<Duration> {
+    let route = request.extensions().get::<MatchedPath>()?.as_str();
+    let route_limit = limiter.config.routes.get(route).copied()?;
+    let (client_id, limit) = match client {
+        Client::Partner(api_key) => (
+            ClientId::ApiKey(api_key.id),
+            RateLimit {
+                requests_per_minute: api_key.requests_per_minute,
+            },
+        ),
+        Client::Anonymous => {
+            let socket = request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(socket)| *socket);
+            let address = limiter.client_ip(request.headers(), socket)?;
+            (ClientId::Address(address), route_limit)
+        }
+    };
+
+    let retry_after = limiter.acquire(route, client_id, limit).err()?;
+    tracing::info!(client = ?client_id, route, "Rate limited");
+    Some(retry_after)
+}
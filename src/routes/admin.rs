@@ -0,0 +1,134 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get};
+use axum::{Json, Router};
+use chrono::Utc;
+use rusqlite::Connection;
+
+use crate::auth::{generate_api_key, AdminToken};
+use crate::database::api_keys::{insert_api_key, list_api_keys, revoke_api_key, ApiKeyUsage};
+use crate::database::db::TaxRateError;
+use crate::messages::{render_message, Language};
+use crate::models::api_keys::{ApiKeyInfo, ApiKeySchema, IssuedApiKey};
+use crate::validators::api_keys::validate_api_key_input;
+
+// The admin routes are unversioned, they are meant for the operators rather than the clients.
+pub fn admin_router() -> Router {
+    Router::new()
+        .route("/admin/api-keys", get(list_keys).post(issue_key))
+        .route("/admin/api-keys/:id", delete(revoke_key))
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/api-keys",
+    request_body = ApiKeySchema,
+    params(("Authorization" = String, Header, description = "`Bearer` followed by the admin token.")),
+    responses(
+        (status = 201, description = "The issued key, it is only shown once.", body = IssuedApiKey),
+        (status = 401, description = "Missing or invalid admin token.", body = String),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 500, description = "The database could not be written.", body = String),
+    )
+)]
+pub async fn issue_key(
+    _admin: AdminToken,
+    language: Language,
+    Json(data): Json<ApiKeySchema>,
+) -> Response {
+    let input = match validate_api_key_input(&data) {
+        Ok(input) => input,
+        Err(report) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response()
+        }
+    };
+
+    let (key, key_hash) = generate_api_key();
+    let api_key = match Connection::open("./tax_rates.db").and_then(|conn| {
+        insert_api_key(
+            &conn,
+            &input.name,
+            &key_hash,
+            &key[..12],
+            input.daily_quota,
+            input.requests_per_minute,
+        )
+    }) {
+        Ok(api_key) => api_key,
+        Err(error) => {
+            return TaxRateError::from(error)
+                .localized(language)
+                .into_response()
+        }
+    };
+    tracing::info!(id = api_key.id, name = api_key.name, "Issued an API key");
+
+    let api_key = ApiKeyInfo::from(ApiKeyUsage {
+        api_key,
+        requests_today: 0,
+        requests_total: 0,
+    });
+    (StatusCode::CREATED, Json(IssuedApiKey { key, api_key })).into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/api-keys",
+    params(("Authorization" = String, Header, description = "`Bearer` followed by the admin token.")),
+    responses(
+        (status = 200, description = "Every issued key with its usage.", body = Vec<ApiKeyInfo>),
+        (status = 401, description = "Missing or invalid admin token.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn list_keys(_admin: AdminToken, language: Language) -> Response {
+    let today = Utc::now().date_naive().to_string();
+    match Connection::open("./tax_rates.db").and_then(|conn| list_api_keys(&conn, &today)) {
+        Ok(api_keys) => Json(
+            api_keys
+                .into_iter()
+                .map(ApiKeyInfo::from)
+                .collect::<Vec<_>>(),
+        )
+        .into_response(),
+        Err(error) => TaxRateError::from(error)
+            .localized(language)
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/admin/api-keys/{id}",
+    params(
+        ("id" = i64, Path, description = "The id of the key."),
+        ("Authorization" = String, Header, description = "`Bearer` followed by the admin token."),
+    ),
+    responses(
+        (status = 204, description = "The key is revoked."),
+        (status = 401, description = "Missing or invalid admin token.", body = String),
+        (status = 404, description = "No active key with this id.", body = String),
+        (status = 500, description = "The database could not be written.", body = String),
+    )
+)]
+pub async fn revoke_key(_admin: AdminToken, language: Language, Path(id): Path<i64>) -> Response {
+    match Connection::open("./tax_rates.db").and_then(|conn| revoke_api_key(&conn, id)) {
+        Ok(true) => {
+            tracing::info!(id, "Revoked an API key");
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Ok(false) => (
+            StatusCode::NOT_FOUND,
+            render_message(language, "api_key_not_found", "", Some(&id.to_string())),
+        )
+            .into_response(),
+        Err(error) => TaxRateError::from(error)
+            .localized(language)
+            .into_response(),
+    }
+}
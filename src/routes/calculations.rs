@@ -3,26 +3,129 @@ use axum::response::{IntoResponse, Response};
 use axum::routing::post;
 use axum::{Json, Router};
 
-use crate::models::calculations::CalculateSchema;
+use crate::database::db_backup::get_current_year;
+use crate::logging::Salary;
+use crate::messages::{breakdown_labels, Language};
+use crate::metrics::record_calculation;
+use crate::models::annual::AnnualSchema;
+use crate::models::calculations::{AppliedRules, CalculateSchema, CalculationResults};
+use crate::services::annual::perform_annual_calculation;
 use crate::services::calculations::perform_calculation;
+use crate::validators::annual::validate_annual_input;
 use crate::validators::calculations::validate_calculate_input;
 
 pub fn calculate_router() -> Router {
-    Router::new().route("/calculate", post(calculate))
+    Router::new()
+        .route("/calculate", post(calculate))
+        .route("/calculate/annual", post(calculate_annual))
 }
 
-pub async fn calculate(Json(data): Json<CalculateSchema>) -> Response {
-    println!("->> {:<12} - Calculate handler - {data:?}", "HANDLER");
-    let calculation_input = match validate_calculate_input(&data) {
-        Ok(data) => data,
-        Err(message) => return (StatusCode::BAD_REQUEST, message).into_response(),
+#[utoipa::path(
+    post,
+    path = "/v1/calculate",
+    request_body = CalculateSchema,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The salary breakdown.", body = CalculationResults),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 404, description = "No tax rates for the requested year.", body = String),
+        (status = 429, description = "Too many requests from the client, retry after `Retry-After` seconds.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn calculate(language: Language, Json(data): Json<CalculateSchema>) -> Response {
+    tracing::debug!(
+        income = %Salary(&data.income),
+        income_type = ?data.income_type,
+        currency = ?data.currency,
+        year = ?data.year,
+        "Calculate handler"
+    );
+    let (calculation_results, _) = match run_calculation(language, &data).await {
+        Ok(calculation) => calculation,
+        Err(response) => return response,
     };
-
-    let calculation_results = perform_calculation(calculation_input).await;
-    println!(
-        "->> {:<12} - Calculate calculation_results - {calculation_results:?}",
-        "DEBUG"
+    tracing::debug!(
+        brute_income = %Salary(calculation_results.brute_income),
+        net_income = %Salary(calculation_results.net_income),
+        "Calculate calculation_results"
     );
 
     Json(calculation_results).into_response()
 }
+
+#[utoipa::path(
+    post,
+    path = "/v1/calculate/annual",
+    request_body = AnnualSchema,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The payslip of every month and the yearly totals.", body = AnnualResults),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 404, description = "No tax rates for the requested year.", body = String),
+        (status = 429, description = "Too many requests from the client, retry after `Retry-After` seconds.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn calculate_annual(language: Language, Json(data): Json<AnnualSchema>) -> Response {
+    tracing::debug!(
+        year = ?data.year,
+        months = data.months.as_ref().map(Vec::len),
+        "Calculate annual handler"
+    );
+    let input = match validate_annual_input(&data) {
+        Ok(input) => input,
+        Err(report) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response()
+        }
+    };
+
+    let mut results = match perform_annual_calculation(input).await {
+        Ok(results) => results,
+        Err(error) => return error.localized(language).into_response(),
+    };
+    results.labels = breakdown_labels(language);
+
+    Json(results).into_response()
+}
+
+// Validates and runs a calculation, the error is the response to send back. Shared by the
+// routes computing a breakdown.
+pub(crate) async fn run_calculation(
+    language: Language,
+    data: &CalculateSchema,
+) -> Result<(CalculationResults, AppliedRules), Response> {
+    let calculation_input = match validate_calculate_input(data) {
+        Ok(data) => data,
+        Err(report) => {
+            return Err((
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response())
+        }
+    };
+
+    // Only the calculations answered are counted.
+    let income_type = format!("{:?}", calculation_input.income_type);
+    let currency = format!("{:?}", calculation_input.currency);
+    let year = calculation_input.year.unwrap_or_else(get_current_year);
+    let (mut calculation_results, rules) = match perform_calculation(calculation_input).await {
+        Ok(calculation) => calculation,
+        Err(error) => return Err(error.localized(language).into_response()),
+    };
+    record_calculation(&income_type, &currency, year);
+    calculation_results.labels = breakdown_labels(language);
+
+    Ok((calculation_results, rules))
+}
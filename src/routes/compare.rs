@@ -0,0 +1,53 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+
+use crate::logging::Salary;
+use crate::messages::Language;
+use crate::models::compare::CompareSchema;
+use crate::services::compare::perform_comparison;
+use crate::validators::compare::validate_compare_input;
+
+pub fn compare_router() -> Router {
+    Router::new().route("/compare", post(compare))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/compare",
+    request_body = CompareSchema,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The yearly outcome of each regime.", body = CompareResults),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 404, description = "No rates for the requested year.", body = String),
+        (status = 429, description = "Too many requests from the client, retry after `Retry-After` seconds.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn compare(language: Language, Json(data): Json<CompareSchema>) -> Response {
+    tracing::debug!(
+        budget = %Salary(&data.budget),
+        year = ?data.year,
+        "Compare handler"
+    );
+    let input = match validate_compare_input(&data) {
+        Ok(input) => input,
+        Err(report) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response()
+        }
+    };
+
+    match perform_comparison(input).await {
+        Ok(results) => Json(results).into_response(),
+        Err(error) => error.localized(language).into_response(),
+    }
+}
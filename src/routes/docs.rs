@@ -0,0 +1,168 @@
+use axum::response::Html;
+use axum::routing::get;
+use axum::{Json, Router};
+use utoipa::openapi::Deprecated;
+use utoipa::{Modify, OpenApi};
+
+use crate::database::db::{BenefitRule, IncomeTaxExemption, PfaThresholds};
+use crate::models::annual::{AnnualResults, AnnualSchema, AnnualTotals, MonthlyPayslip};
+use crate::models::api_keys::{ApiKeyInfo, ApiKeySchema, IssuedApiKey};
+use crate::models::benefits::{BenefitResult, BenefitSchema};
+use crate::models::calculations::{
+    AppliedRules, CalculateSchema, CalculationResults, NumberOrString, TaxInfo, TaxRatesInfo,
+};
+use crate::models::compare::{CompareResults, CompareSchema, Regime, RegimeOutcome};
+use crate::models::health::{ComponentReport, ComponentStatus, ReadinessReport};
+use crate::models::micro_enterprise::{MicroEnterpriseResults, MicroEnterpriseSchema};
+use crate::models::other_income::{
+    OtherIncomeCategoryResult, OtherIncomeResults, OtherIncomeSchema, OtherIncomeSourceSchema,
+};
+use crate::models::payroll::{
+    EmployeePayslip, EmployeeSchema, EmployerSchema, PayrollResults, PayrollSchema, PayrollTotals,
+};
+use crate::models::payslip::{IncomeComponentResult, OvertimeSchema, PaymentSchema};
+use crate::models::real_income::{RealIncomeResults, RealIncomeSchema};
+use crate::models::shared_calculations::SharedCalculation;
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::routes::{
+    admin, calculations, compare, health, metrics, micro_enterprise, other_income, payroll,
+    real_income, shared_calculations, taxes,
+};
+
+// The OpenAPI document is generated from the handlers and the model types,
+// new routes have to be listed here to be documented.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Calven", description = "Romanian salary calculator API."),
+    paths(
+        health::health,
+        health::liveness,
+        health::readiness,
+        calculations::calculate,
+        calculations::calculate_annual,
+        shared_calculations::share_calculation,
+        shared_calculations::fetch_shared_calculation,
+        micro_enterprise::calculate_micro_enterprise,
+        other_income::calculate_other_income,
+        payroll::calculate_payroll,
+        payroll::export_d112,
+        real_income::compare_real_income,
+        compare::compare,
+        taxes::fetch_current_year_tax_rates,
+        taxes::fetch_current_year_tax_info,
+        metrics::metrics,
+        admin::issue_key,
+        admin::list_keys,
+        admin::revoke_key,
+    ),
+    components(schemas(
+        CalculateSchema,
+        NumberOrString,
+        BenefitSchema,
+        PaymentSchema,
+        OvertimeSchema,
+        CalculationResults,
+        AnnualSchema,
+        AnnualResults,
+        MonthlyPayslip,
+        AnnualTotals,
+        BenefitResult,
+        IncomeComponentResult,
+        ValidationReport,
+        ValidationError,
+        TaxRatesInfo,
+        TaxInfo,
+        AppliedRules,
+        BenefitRule,
+        PfaThresholds,
+        IncomeTaxExemption,
+        SharedCalculation,
+        MicroEnterpriseSchema,
+        MicroEnterpriseResults,
+        OtherIncomeSchema,
+        OtherIncomeSourceSchema,
+        OtherIncomeResults,
+        OtherIncomeCategoryResult,
+        PayrollSchema,
+        EmployerSchema,
+        EmployeeSchema,
+        PayrollResults,
+        EmployeePayslip,
+        PayrollTotals,
+        RealIncomeSchema,
+        RealIncomeResults,
+        CompareSchema,
+        CompareResults,
+        RegimeOutcome,
+        Regime,
+        ComponentStatus,
+        ComponentReport,
+        ReadinessReport,
+        ApiKeySchema,
+        ApiKeyInfo,
+        IssuedApiKey,
+    )),
+    modifiers(&LegacyAliases)
+)]
+pub struct ApiDoc;
+
+// The unversioned routes of the legacy router, `/taxes` is documented on its own handler
+// because it keeps the original field names.
+static LEGACY_PATHS: [&str; 4] = ["/health", "/calculate", "/calculate/annual", "/taxes"];
+
+// Documents the legacy routes as deprecated copies of their `/v1` successor.
+struct LegacyAliases;
+
+impl Modify for LegacyAliases {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let paths = &mut openapi.paths.paths;
+        for path in LEGACY_PATHS {
+            let Some(mut path_item) = paths
+                .get(path)
+                .or_else(|| paths.get(&format!("/v1{path}")))
+                .cloned()
+            else {
+                continue;
+            };
+            for operation in path_item.operations.values_mut() {
+                operation.deprecated = Some(Deprecated::True);
+                operation.operation_id = operation
+                    .operation_id
+                    .as_ref()
+                    .map(|operation_id| format!("legacy_{operation_id}"));
+                operation.description = Some(format!(
+                    "Deprecated alias of `/v1{path}`, the JSON properties are in snake_case."
+                ));
+            }
+            paths.insert(path.to_string(), path_item);
+        }
+    }
+}
+
+static REDOC_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>Calven API</title>
+    <meta charset="utf-8"/>
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+  </head>
+  <body>
+    <redoc spec-url="/openapi.json"></redoc>
+    <script src="https://cdn.redoc.ly/redoc/latest/bundles/redoc.standalone.js"></script>
+  </body>
+</html>
+"#;
+
+pub fn docs_router() -> Router {
+    Router::new()
+        .route("/openapi.json", get(openapi))
+        .route("/docs", get(docs))
+}
+
+pub async fn openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+pub async fn docs() -> Html<&'static str> {
+    Html(REDOC_PAGE)
+}
@@ -1,12 +1,63 @@
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::get;
-use axum::Router;
+use axum::{Json, Router};
+
+use crate::messages::{render_message, Language};
+use crate::models::health::ComponentReport;
+use crate::services::health::check_readiness;
 
 pub fn health_router() -> Router {
     Router::new().route("/health", get(health))
 }
 
-pub async fn health() -> impl IntoResponse {
-    (StatusCode::OK, "The server is healthy.")
+#[utoipa::path(
+    get,
+    path = "/v1/health",
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the message, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses((status = 200, description = "The server is up.", body = String))
+)]
+pub async fn health(language: Language) -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        render_message(language, "healthy", "", None),
+    )
+}
+
+// The probes are unversioned, they are meant for the orchestrator rather than the clients.
+pub fn probes_router() -> Router {
+    Router::new()
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    responses((status = 200, description = "The process is running.", body = ComponentReport))
+)]
+pub async fn liveness() -> impl IntoResponse {
+    Json(ComponentReport::up(String::from("The server is running.")))
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    responses(
+        (status = 200, description = "The server can serve calculations.", body = ReadinessReport),
+        (status = 503, description = "A component the calculations need is down.", body = ReadinessReport),
+    )
+)]
+pub async fn readiness() -> impl IntoResponse {
+    let report = check_readiness();
+    let status = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(report))
 }
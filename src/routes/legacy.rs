@@ -0,0 +1,57 @@
+use axum::body::{to_bytes, Body};
+use axum::http::header::{CONTENT_LENGTH, CONTENT_TYPE, LINK};
+use axum::http::{HeaderValue, Uri};
+use axum::middleware::map_response;
+use axum::response::Response;
+use axum::Router;
+
+use crate::routes::calculations::calculate_router;
+use crate::routes::health::health_router;
+use crate::routes::taxes::legacy_taxes_router;
+use crate::utils::to_snake_case_keys;
+
+// The unversioned routes are deprecated aliases of the `/v1` routes. They keep answering
+// with snake_case JSON like before and point clients to their successor until removed.
+pub fn legacy_router() -> Router {
+    Router::new()
+        .merge(calculate_router())
+        .merge(legacy_taxes_router())
+        .layer(map_response(legacy_response))
+}
+
+// Kept apart from the API routes, so that checking the health is not charged to a partner.
+pub fn legacy_health_router() -> Router {
+    health_router().layer(map_response(legacy_response))
+}
+
+async fn legacy_response(uri: Uri, response: Response) -> Response {
+    let (mut parts, body) = response.into_parts();
+    parts
+        .headers
+        .insert("Deprecation", HeaderValue::from_static("true"));
+    if let Ok(link) =
+        HeaderValue::from_str(&format!("</v1{}>; rel=\"successor-version\"", uri.path()))
+    {
+        parts.headers.insert(LINK, link);
+    }
+
+    let is_json = parts
+        .headers
+        .get(CONTENT_TYPE)
+        .is_some_and(|content_type| content_type.as_bytes().starts_with(b"application/json"));
+    if !is_json {
+        return Response::from_parts(parts, body);
+    }
+
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(_) => return Response::from_parts(parts, Body::empty()),
+    };
+    let body = match serde_json::from_slice(&bytes) {
+        Ok(value) => serde_json::to_vec(&to_snake_case_keys(value)).unwrap_or(bytes.to_vec()),
+        Err(_) => bytes.to_vec(),
+    };
+    parts.headers.remove(CONTENT_LENGTH);
+
+    Response::from_parts(parts, Body::from(body))
+}
@@ -0,0 +1,56 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+
+use crate::logging::Salary;
+use crate::messages::Language;
+use crate::models::micro_enterprise::MicroEnterpriseSchema;
+use crate::services::micro_enterprise::perform_micro_enterprise_calculation;
+use crate::validators::micro_enterprise::validate_micro_enterprise_input;
+
+pub fn micro_enterprise_router() -> Router {
+    Router::new().route("/micro-enterprise", post(calculate_micro_enterprise))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/micro-enterprise",
+    request_body = MicroEnterpriseSchema,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The yearly outcome of the micro-enterprise.", body = MicroEnterpriseResults),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 404, description = "No rates for the requested year.", body = String),
+        (status = 429, description = "Too many requests from the client, retry after `Retry-After` seconds.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn calculate_micro_enterprise(
+    language: Language,
+    Json(data): Json<MicroEnterpriseSchema>,
+) -> Response {
+    tracing::debug!(
+        revenue = %Salary(&data.revenue),
+        year = ?data.year,
+        "Micro-enterprise handler"
+    );
+    let input = match validate_micro_enterprise_input(&data) {
+        Ok(input) => input,
+        Err(report) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response()
+        }
+    };
+
+    match perform_micro_enterprise_calculation(input).await {
+        Ok(results) => Json(results).into_response(),
+        Err(error) => error.localized(language).into_response(),
+    }
+}
@@ -1,3 +1,13 @@
+pub mod admin;
 pub mod calculations;
+pub mod compare;
+pub mod docs;
 pub mod health;
+pub mod legacy;
+pub mod metrics;
+pub mod micro_enterprise;
+pub mod other_income;
+pub mod payroll;
+pub mod real_income;
+pub mod shared_calculations;
 pub mod taxes;
@@ -0,0 +1,55 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+
+use crate::messages::Language;
+use crate::models::other_income::OtherIncomeSchema;
+use crate::services::other_income::perform_other_income_calculation;
+use crate::validators::other_income::validate_other_income_input;
+
+pub fn other_income_router() -> Router {
+    Router::new().route("/other-income", post(calculate_other_income))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/other-income",
+    request_body = OtherIncomeSchema,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The yearly taxes owed on the other income.", body = OtherIncomeResults),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 404, description = "No rates for the requested year.", body = String),
+        (status = 429, description = "Too many requests from the client, retry after `Retry-After` seconds.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn calculate_other_income(
+    language: Language,
+    Json(data): Json<OtherIncomeSchema>,
+) -> Response {
+    tracing::debug!(
+        incomes = data.incomes.as_ref().map_or(0, Vec::len),
+        year = ?data.year,
+        "Other income handler"
+    );
+    let input = match validate_other_income_input(&data) {
+        Ok(input) => input,
+        Err(report) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response()
+        }
+    };
+
+    match perform_other_income_calculation(input).await {
+        Ok(results) => Json(results).into_response(),
+        Err(error) => error.localized(language).into_response(),
+    }
+}
@@ -0,0 +1,116 @@
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+
+use crate::database::db_backup::get_current_year;
+use crate::messages::{breakdown_labels, Language};
+use crate::models::payroll::PayrollSchema;
+use crate::services::d112::perform_d112_export;
+use crate::services::payroll::perform_payroll_calculation;
+use crate::validators::payroll::{validate_d112_input, validate_payroll_input};
+
+pub fn payroll_router() -> Router {
+    Router::new()
+        .route("/payroll", post(calculate_payroll))
+        .route("/payroll/d112", post(export_d112))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/payroll",
+    request_body = PayrollSchema,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The payslip of every employee and the employer totals.", body = PayrollResults),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 404, description = "No tax rates for the requested year.", body = String),
+        (status = 429, description = "Too many requests from the client, retry after `Retry-After` seconds.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn calculate_payroll(language: Language, Json(data): Json<PayrollSchema>) -> Response {
+    tracing::debug!(
+        year = ?data.year,
+        month = ?data.month,
+        employees = data.employees.as_ref().map(Vec::len),
+        "Payroll handler"
+    );
+    let input = match validate_payroll_input(&data) {
+        Ok(input) => input,
+        Err(report) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response()
+        }
+    };
+
+    let mut results = match perform_payroll_calculation(input).await {
+        Ok(results) => results,
+        Err(error) => return error.localized(language).into_response(),
+    };
+    results.labels = breakdown_labels(language);
+
+    Json(results).into_response()
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/payroll/d112",
+    request_body = PayrollSchema,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The payroll as a D112-style XML summary, not a filing-ready declaration.", body = String, content_type = "application/xml"),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 404, description = "No tax rates for the requested year.", body = String),
+        (status = 429, description = "Too many requests from the client, retry after `Retry-After` seconds.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn export_d112(language: Language, Json(data): Json<PayrollSchema>) -> Response {
+    tracing::debug!(
+        year = ?data.year,
+        month = ?data.month,
+        employees = data.employees.as_ref().map(Vec::len),
+        "D112 export handler"
+    );
+    let input = match validate_d112_input(&data) {
+        Ok(input) => input,
+        Err(report) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response()
+        }
+    };
+
+    let file_name = format!(
+        "d112-summary-{}-{:02}.xml",
+        input.payroll.year.unwrap_or_else(get_current_year),
+        input.month
+    );
+    match perform_d112_export(input).await {
+        Ok(xml) => (
+            [
+                (CONTENT_TYPE, String::from("application/xml")),
+                (
+                    CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{file_name}\""),
+                ),
+            ],
+            xml,
+        )
+            .into_response(),
+        Err(error) => error.localized(language).into_response(),
+    }
+}
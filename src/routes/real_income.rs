@@ -0,0 +1,58 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+
+use crate::messages::{breakdown_labels, Language};
+use crate::models::real_income::RealIncomeSchema;
+use crate::services::real_income::perform_real_income_comparison;
+use crate::validators::real_income::validate_real_income_input;
+
+pub fn real_income_router() -> Router {
+    Router::new().route("/real-income", post(compare_real_income))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/real-income",
+    request_body = RealIncomeSchema,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The first salary restated in the money of the second year, compared to the second salary.", body = RealIncomeResults),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 404, description = "No tax rates or indices for a requested year.", body = String),
+        (status = 429, description = "Too many requests from the client, retry after `Retry-After` seconds.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn compare_real_income(
+    language: Language,
+    Json(data): Json<RealIncomeSchema>,
+) -> Response {
+    tracing::debug!(
+        from_year = ?data.from.as_ref().and_then(|from| from.year.as_ref()),
+        to_year = ?data.to.as_ref().and_then(|to| to.year.as_ref()),
+        "Real income handler"
+    );
+    let input = match validate_real_income_input(&data) {
+        Ok(input) => input,
+        Err(report) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(report.localize(language)),
+            )
+                .into_response()
+        }
+    };
+
+    let mut results = match perform_real_income_comparison(input).await {
+        Ok(results) => results,
+        Err(error) => return error.localized(language).into_response(),
+    };
+    results.labels = breakdown_labels(language);
+
+    Json(results).into_response()
+}
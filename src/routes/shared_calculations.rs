@@ -0,0 +1,170 @@
+use axum::extract::Path;
+use axum::http::header::LOCATION;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use rand::distributions::{Alphanumeric, DistString};
+use rusqlite::{Connection, ErrorCode};
+use std::env;
+
+use crate::database::db::TaxRateError;
+use crate::database::shared_calculations::{get_shared_calculation, insert_shared_calculation};
+use crate::messages::{render_message, Language};
+use crate::models::calculations::CalculateSchema;
+use crate::models::shared_calculations::SharedCalculation;
+use crate::routes::calculations::run_calculation;
+
+// Shared calculations are kept for SHARED_CALCULATION_TTL_DAYS, 30 by default.
+static DEFAULT_SHARED_CALCULATION_TTL_DAYS: u32 = 30;
+static SHARE_ID_LENGTH: usize = 10;
+
+pub fn shared_calculations_router() -> Router {
+    Router::new()
+        .route("/calculations", post(share_calculation))
+        .route("/calculations/:id", get(fetch_shared_calculation))
+}
+
+fn shared_calculation_ttl_days() -> u32 {
+    env::var("SHARED_CALCULATION_TTL_DAYS")
+        .ok()
+        .and_then(|days| days.parse().ok())
+        .filter(|days| *days > 0)
+        .unwrap_or(DEFAULT_SHARED_CALCULATION_TTL_DAYS)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/calculations",
+    request_body = CalculateSchema,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 201, description = "The saved calculation, its link is in the `Location` header.", body = SharedCalculation),
+        (status = 422, description = "Every invalid field of the request.", body = ValidationReport),
+        (status = 404, description = "No tax rates for the requested year.", body = String),
+        (status = 429, description = "Too many requests from the client, retry after `Retry-After` seconds.", body = String),
+        (status = 500, description = "The database could not be written.", body = String),
+    )
+)]
+pub async fn share_calculation(language: Language, Json(data): Json<CalculateSchema>) -> Response {
+    let (calculation_results, rules) = match run_calculation(language, &data).await {
+        Ok(calculation) => calculation,
+        Err(response) => return response,
+    };
+
+    let conn = match Connection::open("./tax_rates.db") {
+        Ok(conn) => conn,
+        Err(error) => {
+            return TaxRateError::from(error)
+                .localized(language)
+                .into_response()
+        }
+    };
+    let documents = (|| {
+        Ok::<_, serde_json::Error>((
+            serde_json::to_string(&data)?,
+            serde_json::to_string(&rules)?,
+            serde_json::to_string(&calculation_results)?,
+        ))
+    })();
+    let Ok((input, rules, results)) = documents else {
+        return TaxRateError::DatabaseError(String::from(
+            "the calculation could not be serialized",
+        ))
+        .localized(language)
+        .into_response();
+    };
+
+    // The ids are random, a collision is unlikely but simply gets a new id.
+    let mut stored = None;
+    for _ in 0..3 {
+        let id = Alphanumeric.sample_string(&mut rand::thread_rng(), SHARE_ID_LENGTH);
+        match insert_shared_calculation(
+            &conn,
+            &id,
+            &input,
+            &rules,
+            &results,
+            shared_calculation_ttl_days(),
+        ) {
+            Ok(calculation) => {
+                stored = Some(calculation);
+                break;
+            }
+            Err(rusqlite::Error::SqliteFailure(error, _))
+                if error.code == ErrorCode::ConstraintViolation =>
+            {
+                continue
+            }
+            Err(error) => {
+                return TaxRateError::from(error)
+                    .localized(language)
+                    .into_response()
+            }
+        }
+    }
+    let Some(stored) = stored else {
+        return TaxRateError::DatabaseError(String::from("no free id for the calculation"))
+            .localized(language)
+            .into_response();
+    };
+
+    match SharedCalculation::try_from(stored) {
+        Ok(shared_calculation) => (
+            StatusCode::CREATED,
+            [(
+                LOCATION,
+                format!("/v1/calculations/{}", shared_calculation.id),
+            )],
+            Json(shared_calculation),
+        )
+            .into_response(),
+        Err(error) => TaxRateError::DatabaseError(error.to_string())
+            .localized(language)
+            .into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/calculations/{id}",
+    params(
+        ("id" = String, Path, description = "The id of the shared calculation."),
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The saved calculation.", body = SharedCalculation),
+        (status = 404, description = "No calculation with this id, or it expired.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+    )
+)]
+pub async fn fetch_shared_calculation(language: Language, Path(id): Path<String>) -> Response {
+    let stored = match Connection::open("./tax_rates.db")
+        .and_then(|conn| get_shared_calculation(&conn, &id))
+    {
+        Ok(stored) => stored,
+        Err(error) => {
+            return TaxRateError::from(error)
+                .localized(language)
+                .into_response()
+        }
+    };
+    let Some(stored) = stored else {
+        return (
+            StatusCode::NOT_FOUND,
+            render_message(language, "shared_calculation_not_found", "", Some(&id)),
+        )
+            .into_response();
+    };
+
+    match SharedCalculation::try_from(stored) {
+        Ok(shared_calculation) => Json(shared_calculation).into_response(),
+        Err(error) => TaxRateError::DatabaseError(error.to_string())
+            .localized(language)
+            .into_response(),
+    }
+}
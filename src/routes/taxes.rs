@@ -3,19 +3,69 @@ use axum::routing::get;
 use axum::{Json, Router};
 use rusqlite::Connection;
 
-use crate::database::db::get_tax_rates;
+use crate::database::db::{get_cached_tax_rates, TaxRateError, TaxRates};
 use crate::database::db_backup::get_current_year;
-use crate::models::calculations::TaxInfo;
+use crate::messages::Language;
+use crate::models::calculations::{TaxInfo, TaxRatesInfo};
 
 pub fn taxes_router() -> Router {
     Router::new().route("/taxes", get(fetch_current_year_tax_rates))
 }
 
-pub async fn fetch_current_year_tax_rates() -> Response {
+// The deprecated unversioned route keeps the original field names.
+pub fn legacy_taxes_router() -> Router {
+    Router::new().route("/taxes", get(fetch_current_year_tax_info))
+}
+
+// The rates of the current year, a database that cannot be opened makes the route
+// unavailable rather than failing the request.
+fn fetch_tax_rates() -> Result<TaxRates, TaxRateError> {
     let conn = Connection::open("./tax_rates.db")
-        .expect("Sqlite conn should be able to open. Error cause");
-    let tax_rates = get_tax_rates(&conn, get_current_year())
-        .expect("Tax rates for current year should be found in the database. Error cause");
+        .map_err(|error| TaxRateError::DatabaseUnavailable(error.to_string()))?;
+    get_cached_tax_rates(&conn, get_current_year())
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/taxes",
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The tax rates of the current year.", body = TaxRatesInfo),
+        (status = 404, description = "No rates for the current year.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+        (status = 503, description = "The database could not be opened.", body = String),
+    )
+)]
+pub async fn fetch_current_year_tax_rates(language: Language) -> Response {
+    let tax_rates = match fetch_tax_rates() {
+        Ok(tax_rates) => tax_rates,
+        Err(error) => return error.localized(language).into_response(),
+    };
+    Json(TaxRatesInfo::from(&tax_rates)).into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/taxes",
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the messages, `en` or `ro`."),
+        ("Accept-Language" = Option<String>, Header, description = "Used when `lang` is not given."),
+    ),
+    responses(
+        (status = 200, description = "The tax rates of the current year.", body = TaxInfo),
+        (status = 404, description = "No rates for the current year.", body = String),
+        (status = 500, description = "The database could not be read.", body = String),
+        (status = 503, description = "The database could not be opened.", body = String),
+    )
+)]
+pub async fn fetch_current_year_tax_info(language: Language) -> Response {
+    let tax_rates = match fetch_tax_rates() {
+        Ok(tax_rates) => tax_rates,
+        Err(error) => return error.localized(language).into_response(),
+    };
     let tax_info = TaxInfo {
         year: &tax_rates.year,
         cas: &tax_rates.health_insurance,
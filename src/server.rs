@@ -0,0 +1,107 @@
+use axum::Router;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+use std::future::IntoFuture;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+// How long the in-flight requests get to finish once a shutdown signal is received.
+static DEFAULT_DRAIN_TIMEOUT_SECONDS: u64 = 30;
+
+#[derive(Debug)]
+pub enum StartupError {
+    DatabaseOpen(rusqlite::Error),
+    Migration(rusqlite::Error),
+    Bind(String, std::io::Error),
+    Serve(std::io::Error),
+}
+
+impl StartupError {
+    // Each failure has its own exit code so that supervisors can tell them apart.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            StartupError::DatabaseOpen(_) => 2,
+            StartupError::Migration(_) => 3,
+            StartupError::Bind(_, _) => 4,
+            StartupError::Serve(_) => 5,
+        }
+    }
+}
+
+impl fmt::Display for StartupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StartupError::DatabaseOpen(ref err) => {
+                write!(f, "Could not open the database: {}", err)
+            }
+            StartupError::Migration(ref err) => write!(f, "Could not set up the database: {}", err),
+            StartupError::Bind(ref address, ref err) => {
+                write!(f, "Could not listen on {}: {}", address, err)
+            }
+            StartupError::Serve(ref err) => write!(f, "Server error: {}", err),
+        }
+    }
+}
+
+impl Error for StartupError {}
+
+// Serves until SIGTERM or SIGINT, then stops accepting connections and waits for the
+// in-flight requests up to the drain timeout (DRAIN_TIMEOUT_SECONDS, 30 by default).
+pub async fn serve(listener: TcpListener, router: Router) -> Result<(), StartupError> {
+    let drain_timeout = Duration::from_secs(
+        env::var("DRAIN_TIMEOUT_SECONDS")
+            .ok()
+            .and_then(|seconds| seconds.parse().ok())
+            .unwrap_or(DEFAULT_DRAIN_TIMEOUT_SECONDS),
+    );
+    let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+    let server = axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(async move {
+        shutdown_signal().await;
+        let _ = shutdown_sender.send(());
+    })
+    .into_future();
+
+    tokio::select! {
+        result = server => result.map_err(StartupError::Serve)?,
+        _ = async {
+            let _ = shutdown_receiver.await;
+            tokio::time::sleep(drain_timeout).await;
+        } => {
+            tracing::warn!("Drain timeout elapsed, dropping the remaining connections");
+        }
+    }
+
+    tracing::info!("Server stopped");
+    Ok(())
+}
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("The SIGINT handler should install");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("The SIGTERM handler should install")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => tracing::info!("Received SIGINT, shutting down"),
+        _ = terminate => tracing::info!("Received SIGTERM, shutting down"),
+    }
+}
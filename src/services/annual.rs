@@ -0,0 +1,32 @@
+use crate::database::db::TaxRateError;
+use crate::database::db_backup::get_current_year;
+use crate::models::annual::{AnnualInput, AnnualResults, AnnualTotals, MonthlyPayslip};
+use crate::services::calculations::calculate_with_connection;
+use rusqlite::Connection;
+use std::collections::BTreeMap;
+
+// Computes the payslip of every month with the rates in force that month, and the yearly
+// totals of the payslips.
+pub async fn perform_annual_calculation(input: AnnualInput) -> Result<AnnualResults, TaxRateError> {
+    let year = input.year.unwrap_or_else(get_current_year);
+    let conn = Connection::open("./tax_rates.db")
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+    let mut months = Vec::new();
+    let mut totals = AnnualTotals::default();
+
+    for (month, mut month_input) in (1..).zip(input.months) {
+        month_input.year = Some(year);
+        month_input.month = Some(month);
+        let (payslip, _) = calculate_with_connection(&conn, month_input)
+            .map_err(|error| error.within(&format!("months[{}]", month - 1)))?;
+        totals.add(&payslip);
+        months.push(MonthlyPayslip { month, payslip });
+    }
+
+    Ok(AnnualResults {
+        year,
+        months,
+        totals: totals.apply_rounding(2),
+        labels: BTreeMap::new(),
+    })
+}
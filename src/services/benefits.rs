@@ -0,0 +1,50 @@
+use crate::database::db::{BenefitRule, TaxRateError, TaxRates};
+use crate::models::benefits::{Benefit, BenefitResult};
+
+pub fn calculate_benefits(
+    benefits: &[Benefit],
+    benefit_rules: &[BenefitRule],
+    tax_rates: &TaxRates,
+) -> Result<Vec<BenefitResult>, TaxRateError> {
+    benefits
+        .iter()
+        .map(|benefit| {
+            let benefit_type = benefit.benefit_type.as_str();
+            let rule = benefit_rules
+                .iter()
+                .find(|rule| rule.benefit_type == benefit_type)
+                .ok_or_else(|| TaxRateError::BenefitRuleNotFound(benefit_type.to_string()))?;
+
+            let value = benefit.value();
+            let cas = if rule.cas {
+                value * tax_rates.social_security
+            } else {
+                0.0
+            };
+            let cass = if rule.cass {
+                value * tax_rates.health_insurance
+            } else {
+                0.0
+            };
+            let income_tax = if rule.income_tax {
+                (value - cas - cass) * tax_rates.income_tax
+            } else {
+                0.0
+            };
+            let cam = if rule.cam {
+                value * tax_rates.insurance_contribution
+            } else {
+                0.0
+            };
+
+            Ok(BenefitResult {
+                benefit_type,
+                value,
+                cas,
+                cass,
+                income_tax,
+                cam,
+            })
+        })
+        .collect()
+}
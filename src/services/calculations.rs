@@ -1,63 +1,231 @@
-use crate::database::db::get_tax_rates;
+use crate::database::db::{
+    get_benefit_rules, get_cached_tax_rates, get_income_tax_exemptions, get_pfa_thresholds,
+    get_tax_rates_for_month, BenefitRule, IncomeTaxExemption, TaxRateError, TaxRates,
+};
 use crate::database::db_backup::get_current_year;
-use crate::models::calculations::{CalculationInput, CalculationResults, IncomeType};
+use crate::logging::Salary;
+use crate::models::benefits::BenefitResult;
+use crate::models::calculations::{AppliedRules, CalculationInput, CalculationResults, IncomeType};
+use crate::models::payslip::IncomeComponentResult;
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::services::benefits::calculate_benefits;
+use crate::services::payslip::calculate_income_components;
+use crate::services::pfa::calculate_pfa;
+use chrono::{Local, NaiveDate};
 use rusqlite::Connection;
+use std::collections::BTreeMap;
 
-pub async fn perform_calculation(input: CalculationInput) -> CalculationResults {
-    // The main function where the calculation works.
-    println!(
-        "->> {:<12} - Calculate calculation_input - {input:?}",
-        "DEBUG in perform_calculation"
+// Runs the calculation and returns the rates and rules it applied next to the results.
+pub async fn perform_calculation(
+    input: CalculationInput,
+) -> Result<(CalculationResults, AppliedRules), TaxRateError> {
+    let conn = Connection::open("./tax_rates.db")
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+    calculate_with_connection(&conn, input)
+}
+
+// The main function where the calculation works, callers running several calculations
+// share the connection.
+pub fn calculate_with_connection(
+    conn: &Connection,
+    input: CalculationInput,
+) -> Result<(CalculationResults, AppliedRules), TaxRateError> {
+    tracing::debug!(
+        income = %Salary(input.income),
+        income_type = ?input.income_type,
+        year = ?input.year,
+        benefits = input.benefits.len(),
+        "perform_calculation input"
     );
-    let conn =
-        Connection::open("./tax_rates.db").expect("Sqlite conn should be able to open. Cause");
-    let tax_rates = get_tax_rates(&conn, input.year.unwrap_or_else(get_current_year))
-        .expect("Tax rates for current year should be found in the database. Cause");
-
-    if input.income_type == IncomeType::NET {
-        let net_income = input.income as f64;
-        let brute_income = net_income
-            / ((1.0 - tax_rates.social_security - tax_rates.health_insurance)
-                * (1.0 - tax_rates.income_tax));
-
-        let calculated_cas = brute_income * tax_rates.social_security;
-        let calculated_cass = brute_income * tax_rates.health_insurance;
-        let calculated_cam_tax = brute_income * tax_rates.insurance_contribution;
-        let taxable_income = brute_income - calculated_cas - calculated_cass;
-        let calculated_income_tax = taxable_income * tax_rates.income_tax;
-        let total_salary = brute_income + calculated_cam_tax;
-        CalculationResults {
-            net_income,
-            brute_income,
-            cas: calculated_cas,
-            cass: calculated_cass,
-            income_tax: calculated_income_tax,
-            cam: calculated_cam_tax,
-            total_salary: brute_income + calculated_cam_tax,
-            employee_tax_percentage: net_income * 100f64 / total_salary,
-            state_tax_percentage: ((total_salary - net_income) * 100f64) / total_salary,
+    let year = input.year.unwrap_or_else(get_current_year);
+    let tax_rates = match input.month {
+        Some(month) => get_tax_rates_for_month(conn, year, month)?,
+        None => get_cached_tax_rates(conn, year)?,
+    };
+    let mut rules = AppliedRules {
+        tax_rates: (&tax_rates).into(),
+        pfa_thresholds: None,
+        benefit_rules: Vec::new(),
+        exemption: None,
+    };
+    if input.income_type == IncomeType::PFA {
+        let thresholds = get_pfa_thresholds(conn, year)?;
+        let results =
+            calculate_pfa(input.income, input.expenses, &tax_rates, &thresholds).apply_rounding(2);
+        rules.pfa_thresholds = Some(thresholds);
+        return Ok((results, rules));
+    }
+    rules.benefit_rules = get_benefit_rules(conn, year)?;
+
+    // The first exemption the employee qualifies for removes the income tax, rules with an
+    // income limit only apply while the brute income stays under it.
+    let age = input
+        .birth_date
+        .and_then(|birth_date| reference_date(year, input.month).years_since(birth_date));
+    for exemption in get_income_tax_exemptions(conn, year)? {
+        if !is_eligible(&exemption, age, input.disability) {
+            continue;
+        }
+        let exempt_rates = TaxRates {
+            income_tax: 0.0,
+            ..tax_rates.clone()
+        };
+        let results = calculate_salary(&input, &rules.benefit_rules, &exempt_rates)?;
+        if exemption
+            .max_brute_income
+            .is_none_or(|max_brute_income| results.brute_income <= max_brute_income)
+        {
+            let results = CalculationResults {
+                exemption: Some(exemption.exemption_type.clone()),
+                ..results
+            };
+            rules.exemption = Some(exemption);
+            return Ok((results, rules));
+        }
+    }
+
+    let results = calculate_salary(&input, &rules.benefit_rules, &tax_rates)?;
+    Ok((results, rules))
+}
+
+fn calculate_salary(
+    input: &CalculationInput,
+    benefit_rules: &[BenefitRule],
+    tax_rates: &TaxRates,
+) -> Result<CalculationResults, TaxRateError> {
+    let benefits = calculate_benefits(&input.benefits, benefit_rules, tax_rates)?;
+
+    // The income is the base salary, the other payslip components are always brute.
+    let base_brute_income = match input.income_type {
+        IncomeType::NET => {
+            // Benefits are not paid in cash, so their taxes are withheld from the salary
+            // and the brute income has to cover them on top of the requested net income.
+            let benefits_deductions: f64 = benefits
+                .iter()
+                .map(BenefitResult::employee_deductions)
+                .sum();
+            (input.income + benefits_deductions)
+                / ((1.0 - tax_rates.social_security - tax_rates.health_insurance)
+                    * (1.0 - tax_rates.income_tax))
         }
-        .apply_rounding(2)
-    } else {
-        let brute_income = input.income as f64;
-        let calculated_cas = brute_income * tax_rates.social_security;
-        let calculated_cass = brute_income * tax_rates.health_insurance;
-        let taxable_income = brute_income - calculated_cas - calculated_cass;
-        let calculated_cam_tax = brute_income * tax_rates.insurance_contribution;
-        let calculated_income_tax = taxable_income * tax_rates.income_tax;
-        let net_income = taxable_income - calculated_income_tax;
-        let total_salary = brute_income + calculated_cam_tax;
-        CalculationResults {
-            brute_income,
-            net_income,
-            total_salary,
-            cas: calculated_cas,
-            cass: calculated_cass,
-            income_tax: calculated_income_tax,
-            cam: calculated_cam_tax,
-            employee_tax_percentage: (net_income * 100f64 / total_salary),
-            state_tax_percentage: ((total_salary - net_income) * 100f64) / total_salary,
+        IncomeType::TOTAL_COST => {
+            // The employer cost grows linearly with the base salary, through CAM and the
+            // overtime paid on its hourly rate, so two evaluations give the solution.
+            let fixed_cost = employer_cost(
+                &calculate_income_components(0.0, input, tax_rates),
+                &benefits,
+            );
+            let cost_per_unit = employer_cost(
+                &calculate_income_components(1.0, input, tax_rates),
+                &benefits,
+            ) - fixed_cost;
+            if input.income < fixed_cost {
+                let mut report = ValidationReport::default();
+                report.push(ValidationError::new(
+                    "income",
+                    "total_cost_too_low",
+                    "income",
+                    Some(input.income.to_string()),
+                ));
+                return Err(TaxRateError::InvalidInput(report));
+            }
+            (input.income - fixed_cost) / cost_per_unit
         }
-        .apply_rounding(2)
+        IncomeType::BRUTE | IncomeType::PFA => input.income,
+    };
+
+    let income_components = calculate_income_components(base_brute_income, input, tax_rates);
+
+    Ok(calculate_totals(income_components, benefits).apply_rounding(2))
+}
+
+// The age is taken at the start of the calculated month, today for the current year, or at
+// the start of any other year.
+fn reference_date(year: u32, month: Option<u32>) -> NaiveDate {
+    let today = Local::now().date_naive();
+    match month {
+        Some(month) => NaiveDate::from_ymd_opt(year as i32, month, 1),
+        None if year == get_current_year() => Some(today),
+        None => NaiveDate::from_ymd_opt(year as i32, 1, 1),
+    }
+    .unwrap_or(today)
+}
+
+fn is_eligible(exemption: &IncomeTaxExemption, age: Option<u32>, disability: bool) -> bool {
+    let age_matches = match exemption.max_age {
+        Some(max_age) => age.is_some_and(|age| age <= max_age),
+        None => true,
+    };
+    age_matches && (disability || !exemption.requires_disability)
+}
+
+// The total salary paid by the employer, as reported by calculate_totals.
+fn employer_cost(income_components: &[IncomeComponentResult], benefits: &[BenefitResult]) -> f64 {
+    income_components
+        .iter()
+        .map(|component| component.brute_income + component.cam)
+        .chain(benefits.iter().map(|benefit| benefit.value + benefit.cam))
+        .sum()
+}
+
+fn calculate_totals(
+    income_components: Vec<IncomeComponentResult>,
+    benefits: Vec<BenefitResult>,
+) -> CalculationResults {
+    let brute_income: f64 = income_components
+        .iter()
+        .map(|component| component.brute_income)
+        .sum();
+    let calculated_cas: f64 = income_components
+        .iter()
+        .map(|component| component.cas)
+        .sum();
+    let calculated_cass: f64 = income_components
+        .iter()
+        .map(|component| component.cass)
+        .sum();
+    let calculated_cam_tax: f64 = income_components
+        .iter()
+        .map(|component| component.cam)
+        .sum();
+    let calculated_income_tax: f64 = income_components
+        .iter()
+        .map(|component| component.income_tax)
+        .sum();
+    let components_net_income: f64 = income_components
+        .iter()
+        .map(|component| component.net_income)
+        .sum();
+
+    let benefits_value: f64 = benefits.iter().map(|benefit| benefit.value).sum();
+    let benefits_deductions: f64 = benefits
+        .iter()
+        .map(BenefitResult::employee_deductions)
+        .sum();
+    let benefits_cas: f64 = benefits.iter().map(|benefit| benefit.cas).sum();
+    let benefits_cass: f64 = benefits.iter().map(|benefit| benefit.cass).sum();
+    let benefits_income_tax: f64 = benefits.iter().map(|benefit| benefit.income_tax).sum();
+    let benefits_cam: f64 = benefits.iter().map(|benefit| benefit.cam).sum();
+
+    let net_income = components_net_income - benefits_deductions;
+    let take_home = net_income + benefits_value;
+    let total_salary = brute_income + calculated_cam_tax + benefits_cam + benefits_value;
+    CalculationResults {
+        brute_income,
+        net_income,
+        total_salary,
+        cas: calculated_cas + benefits_cas,
+        cass: calculated_cass + benefits_cass,
+        income_tax: calculated_income_tax + benefits_income_tax,
+        cam: calculated_cam_tax + benefits_cam,
+        employee_tax_percentage: (take_home * 100f64 / total_salary),
+        state_tax_percentage: ((total_salary - take_home) * 100f64) / total_salary,
+        benefits,
+        benefits_value,
+        take_home,
+        expenses: 0.0,
+        exemption: None,
+        income_components,
+        labels: BTreeMap::new(),
     }
 }
@@ -0,0 +1,96 @@
+use crate::database::db::TaxRateError;
+use crate::database::db_backup::get_current_year;
+use crate::models::calculations::{CalculationInput, Currency, IncomeType};
+use crate::models::compare::{CompareInput, CompareResults, Regime, RegimeOutcome};
+use crate::models::micro_enterprise::MicroEnterpriseInput;
+use crate::models::payslip::DEFAULT_WORKING_HOURS;
+use crate::services::calculations::perform_calculation;
+use crate::services::micro_enterprise::perform_micro_enterprise_calculation;
+
+// Computes the yearly outcome of the same budget as a job, a PFA and a micro-enterprise. The
+// PFA and the company deduct the expenses, the job gets what is left of the budget as the
+// employer cost.
+pub async fn perform_comparison(input: CompareInput) -> Result<CompareResults, TaxRateError> {
+    let year = input.year.unwrap_or_else(get_current_year);
+    let available = (input.budget - input.expenses).max(0.0);
+    let effective_tax_rate = |total_taxes: f64| {
+        if available > 0.0 {
+            total_taxes * 100f64 / available
+        } else {
+            0.0
+        }
+    };
+
+    // The job is monthly, the whole budget left is the employer cost.
+    let (employment, _) =
+        perform_calculation(regime_input(available / 12.0, IncomeType::TOTAL_COST, year)).await?;
+    let employment_taxes =
+        employment.cas + employment.cass + employment.income_tax + employment.cam;
+
+    let (pfa, _) = perform_calculation(CalculationInput {
+        expenses: input.expenses,
+        ..regime_input(input.budget, IncomeType::PFA, year)
+    })
+    .await?;
+    let pfa_taxes = pfa.cas + pfa.cass + pfa.income_tax;
+
+    let micro = perform_micro_enterprise_calculation(MicroEnterpriseInput {
+        revenue: input.budget,
+        expenses: input.expenses,
+        year: Some(year),
+    })
+    .await?;
+
+    let outcomes: Vec<RegimeOutcome> = [
+        (
+            Regime::CIM,
+            employment.net_income * 12.0,
+            employment_taxes * 12.0,
+        ),
+        (Regime::PFA, pfa.net_income, pfa_taxes),
+        (Regime::MICRO, micro.take_home, micro.total_taxes),
+    ]
+    .into_iter()
+    .map(|(regime, net_income, total_taxes)| {
+        RegimeOutcome {
+            regime,
+            net_income,
+            total_taxes,
+            effective_tax_rate: effective_tax_rate(total_taxes),
+        }
+        .apply_rounding(2)
+    })
+    .collect();
+    let best = outcomes
+        .iter()
+        .max_by(|first, second| first.net_income.total_cmp(&second.net_income))
+        .map(|outcome| outcome.regime)
+        .unwrap_or(Regime::CIM);
+
+    Ok(CompareResults {
+        budget: input.budget,
+        expenses: input.expenses,
+        year,
+        outcomes,
+        best,
+    })
+}
+
+fn regime_input(income: f64, income_type: IncomeType, year: u32) -> CalculationInput {
+    CalculationInput {
+        income,
+        income_type,
+        currency: Currency::RON,
+        year: Some(year),
+        month: None,
+        custom_tax: None,
+        benefits: Vec::new(),
+        bonuses: Vec::new(),
+        overtime: Vec::new(),
+        one_off_payments: Vec::new(),
+        working_hours: DEFAULT_WORKING_HOURS,
+        expenses: 0.0,
+        birth_date: None,
+        disability: false,
+    }
+}
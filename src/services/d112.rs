@@ -0,0 +1,108 @@
+use crate::database::db::TaxRateError;
+use crate::models::calculations::CalculationResults;
+use crate::models::payroll::{D112Input, EmployeePayslip, Employer, PayrollResults};
+use crate::services::payroll::perform_payroll_calculation;
+
+// Our own namespace: the file follows the structure of the D112 return to help filling it in,
+// but it is not validated against the ANAF schema and can't be filed as is.
+static D112_NAMESPACE: &str = "urn:calven:d112-summary:v1";
+
+type Obligation = (&'static str, fn(&EmployeePayslip) -> f64);
+
+// The obligation codes of the contributions declared by the employer.
+static OBLIGATION_CODES: [Obligation; 4] = [
+    ("412", |employee| employee.payslip.cas),
+    ("432", |employee| employee.payslip.cass),
+    ("480", |employee| employee.payslip.cam),
+    ("602", |employee| employee.payslip.income_tax),
+];
+
+pub async fn perform_d112_export(input: D112Input) -> Result<String, TaxRateError> {
+    let results = perform_payroll_calculation(input.payroll).await?;
+    Ok(build_d112_xml(&input.employer, input.month, &results))
+}
+
+// The return is declared in whole lei, the employer owes the sum of the rounded amounts of
+// its employees so that both sections agree.
+pub fn build_d112_xml(employer: &Employer, month: u32, results: &PayrollResults) -> String {
+    let lei = |amount: f64| amount.round().max(0.0) as u64;
+
+    let obligations: Vec<(&str, u64)> = OBLIGATION_CODES
+        .iter()
+        .map(|(code, amount)| {
+            (
+                *code,
+                results
+                    .employees
+                    .iter()
+                    .map(|employee| lei(amount(employee)))
+                    .sum(),
+            )
+        })
+        .collect();
+    let total_payment: u64 = obligations.iter().map(|(_, amount)| amount).sum();
+    let total_brute: u64 = results
+        .employees
+        .iter()
+        .map(|employee| lei(taxable_income(&employee.payslip)))
+        .sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<declaratieUnica xmlns=\"{D112_NAMESPACE}\" luna_r=\"{month}\" an_r=\"{}\" totalPlata_A=\"{total_payment}\">\n",
+        results.year
+    ));
+    xml.push_str(&format!(
+        "  <angajator cif=\"{}\" den=\"{}\" nrAsig=\"{}\" totalBrut=\"{total_brute}\">\n",
+        escape(&employer.cif),
+        escape(&employer.name),
+        results.employees.len()
+    ));
+    for (code, amount) in &obligations {
+        xml.push_str(&format!(
+            "    <angajatorA A_codOblig=\"{code}\" A_datorat=\"{amount}\"/>\n"
+        ));
+    }
+    xml.push_str("  </angajator>\n");
+    for employee in &results.employees {
+        let payslip = &employee.payslip;
+        xml.push_str(&format!(
+            "  <asigurat cnpAsig=\"{}\" numeAsig=\"{}\">\n",
+            escape(employee.cnp.as_deref().unwrap_or_default()),
+            escape(&employee.name)
+        ));
+        xml.push_str(&format!(
+            "    <asiguratA venitBrut=\"{}\" cas=\"{}\" cass=\"{}\" impozit=\"{}\" cam=\"{}\" venitNet=\"{}\"/>\n",
+            lei(taxable_income(payslip)),
+            lei(payslip.cas),
+            lei(payslip.cass),
+            lei(payslip.income_tax),
+            lei(payslip.cam),
+            lei(payslip.net_income)
+        ));
+        xml.push_str("  </asigurat>\n");
+    }
+    xml.push_str("</declaratieUnica>\n");
+    xml
+}
+
+// The brute income with the benefits that owe contributions, which are declared with the
+// contributions of the salary.
+fn taxable_income(payslip: &CalculationResults) -> f64 {
+    let taxable_benefits: f64 = payslip
+        .benefits
+        .iter()
+        .filter(|benefit| benefit.cas + benefit.cass + benefit.income_tax + benefit.cam > 0.0)
+        .map(|benefit| benefit.value)
+        .sum();
+    payslip.brute_income + taxable_benefits
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -0,0 +1,48 @@
+use crate::database::db::{get_schema_version, get_tax_rates, SCHEMA_VERSION};
+use crate::database::db_backup::get_current_year;
+use crate::models::health::{ComponentReport, ComponentStatus, ReadinessReport};
+use rusqlite::{Connection, OpenFlags};
+
+// Checks everything the calculations need. The database is opened without the create
+// flag, so a missing file is reported instead of being replaced by an empty database.
+pub fn check_readiness() -> ReadinessReport {
+    let conn = match Connection::open_with_flags(
+        "./tax_rates.db",
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .and_then(|conn| conn.query_row("SELECT 1", [], |_| Ok(())).map(|_| conn))
+    {
+        Ok(conn) => conn,
+        Err(error) => {
+            let skipped = || ComponentReport::down(String::from("Database unavailable."));
+            return ReadinessReport {
+                ready: false,
+                database: ComponentReport::down(error.to_string()),
+                schema: skipped(),
+                tax_rates: skipped(),
+            };
+        }
+    };
+
+    let schema = match get_schema_version(&conn) {
+        Ok(version) if version == SCHEMA_VERSION => {
+            ComponentReport::up(format!("Schema version {version}."))
+        }
+        Ok(version) => ComponentReport::down(format!(
+            "Schema version {version}, expected {SCHEMA_VERSION}."
+        )),
+        Err(error) => ComponentReport::down(error.to_string()),
+    };
+    let year = get_current_year();
+    let tax_rates = match get_tax_rates(&conn, year) {
+        Ok(_) => ComponentReport::up(format!("Tax rates found for {year}.")),
+        Err(error) => ComponentReport::down(format!("{error} ({year})")),
+    };
+
+    ReadinessReport {
+        ready: schema.status == ComponentStatus::Up && tax_rates.status == ComponentStatus::Up,
+        database: ComponentReport::up(String::from("Connected.")),
+        schema,
+        tax_rates,
+    }
+}
@@ -0,0 +1,82 @@
+use crate::database::db::{
+    get_cached_tax_rates, get_micro_enterprise_rates, MicroEnterpriseRates, TaxRateError, TaxRates,
+};
+use crate::database::db_backup::get_current_year;
+use crate::models::micro_enterprise::{MicroEnterpriseInput, MicroEnterpriseResults};
+use rusqlite::Connection;
+
+pub async fn perform_micro_enterprise_calculation(
+    input: MicroEnterpriseInput,
+) -> Result<MicroEnterpriseResults, TaxRateError> {
+    let conn = Connection::open("./tax_rates.db")
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+    let year = input.year.unwrap_or_else(get_current_year);
+    let tax_rates = get_cached_tax_rates(&conn, year)?;
+    let rates = get_micro_enterprise_rates(&conn, year)?;
+
+    Ok(
+        calculate_micro_enterprise(input.revenue, input.expenses, &tax_rates, &rates)
+            .apply_rounding(2),
+    )
+}
+
+// The company pays the turnover tax on its revenue and employs the associate on the minimum
+// wage, what is left of the profit is paid out as dividends.
+pub fn calculate_micro_enterprise(
+    revenue: f64,
+    expenses: f64,
+    tax_rates: &TaxRates,
+    rates: &MicroEnterpriseRates,
+) -> MicroEnterpriseResults {
+    let turnover_tax = revenue * rates.turnover_tax;
+
+    let gross_salary = rates.minimum_wage * 12.0;
+    let salary_cas = gross_salary * tax_rates.social_security;
+    let salary_cass = gross_salary * tax_rates.health_insurance;
+    let salary_income_tax = (gross_salary - salary_cas - salary_cass) * tax_rates.income_tax;
+    let salary_taxes = salary_cas + salary_cass + salary_income_tax;
+    let cam = gross_salary * tax_rates.insurance_contribution;
+    let salary_costs = gross_salary + cam;
+    let net_salary = gross_salary - salary_taxes;
+
+    let distributable_dividend = (revenue - expenses - turnover_tax - salary_costs).max(0.0);
+    let dividend_tax = distributable_dividend * rates.dividend_tax;
+
+    // CASS on dividends is due on a fixed base depending on the bracket reached.
+    let minimum_wage = rates.minimum_wage;
+    let cass_base = if distributable_dividend >= rates.cass_upper_wages * minimum_wage {
+        rates.cass_upper_wages * minimum_wage
+    } else if distributable_dividend >= rates.cass_middle_wages * minimum_wage {
+        rates.cass_middle_wages * minimum_wage
+    } else if distributable_dividend >= rates.cass_lower_wages * minimum_wage {
+        rates.cass_lower_wages * minimum_wage
+    } else {
+        0.0
+    };
+    let cass = cass_base * tax_rates.health_insurance;
+    let net_dividend = distributable_dividend - dividend_tax - cass;
+
+    let total_taxes = turnover_tax + salary_taxes + cam + dividend_tax + cass;
+    let budget = revenue - expenses;
+    MicroEnterpriseResults {
+        revenue,
+        expenses,
+        turnover_tax,
+        gross_salary,
+        salary_taxes,
+        cam,
+        salary_costs,
+        net_salary,
+        distributable_dividend,
+        dividend_tax,
+        cass,
+        net_dividend,
+        take_home: net_salary + net_dividend,
+        total_taxes,
+        effective_tax_rate: if budget > 0.0 {
+            total_taxes * 100f64 / budget
+        } else {
+            0.0
+        },
+    }
+}
@@ -1 +1,12 @@
+pub mod annual;
+pub mod benefits;
 pub mod calculations;
+pub mod compare;
+pub mod d112;
+pub mod health;
+pub mod micro_enterprise;
+pub mod other_income;
+pub mod payroll;
+pub mod payslip;
+pub mod pfa;
+pub mod real_income;
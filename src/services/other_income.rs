@@ -0,0 +1,93 @@
+use crate::database::db::{
+    get_cached_tax_rates, get_other_income_rates, get_other_income_thresholds, OtherIncomeRate,
+    OtherIncomeThresholds, TaxRateError, TaxRates,
+};
+use crate::database::db_backup::get_current_year;
+use crate::models::other_income::{
+    OtherIncomeCategoryResult, OtherIncomeInput, OtherIncomeResults, OtherIncomeSource,
+};
+use rusqlite::Connection;
+
+pub async fn perform_other_income_calculation(
+    input: OtherIncomeInput,
+) -> Result<OtherIncomeResults, TaxRateError> {
+    let conn = Connection::open("./tax_rates.db")
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+    let year = input.year.unwrap_or_else(get_current_year);
+    let tax_rates = get_cached_tax_rates(&conn, year)?;
+    let thresholds = get_other_income_thresholds(&conn, year)?;
+    let rates = get_other_income_rates(&conn, year)?;
+
+    Ok(
+        calculate_other_income(year, &input.incomes, &tax_rates, &thresholds, &rates)?
+            .apply_rounding(2),
+    )
+}
+
+// Each category is taxed on its own, CASS is due once on the total of the categories.
+pub fn calculate_other_income(
+    year: u32,
+    incomes: &[OtherIncomeSource],
+    tax_rates: &TaxRates,
+    thresholds: &OtherIncomeThresholds,
+    rates: &[OtherIncomeRate],
+) -> Result<OtherIncomeResults, TaxRateError> {
+    let mut categories: Vec<OtherIncomeCategoryResult> = Vec::new();
+    for source in incomes {
+        let category = source.category.as_str();
+        let rate = rates
+            .iter()
+            .find(|rate| rate.category == category)
+            .ok_or_else(|| TaxRateError::CategoryRateNotFound(category.to_string(), year))?;
+        let taxable_income = source.amount * (1.0 - rate.flat_deduction);
+
+        // Several incomes of the same category are declared together.
+        match categories
+            .iter_mut()
+            .find(|result| result.category == category)
+        {
+            Some(result) => {
+                result.amount += source.amount;
+                result.taxable_income += taxable_income;
+                result.income_tax += taxable_income * rate.income_tax;
+            }
+            None => categories.push(OtherIncomeCategoryResult {
+                category,
+                amount: source.amount,
+                taxable_income,
+                income_tax: taxable_income * rate.income_tax,
+                withheld_at_source: rate.withheld_at_source,
+            }),
+        }
+    }
+
+    let taxable_income: f64 = categories.iter().map(|result| result.taxable_income).sum();
+    let minimum_wage = thresholds.minimum_wage;
+    let cass_base = if taxable_income >= thresholds.cass_upper_wages * minimum_wage {
+        thresholds.cass_upper_wages * minimum_wage
+    } else if taxable_income >= thresholds.cass_middle_wages * minimum_wage {
+        thresholds.cass_middle_wages * minimum_wage
+    } else if taxable_income >= thresholds.cass_lower_wages * minimum_wage {
+        thresholds.cass_lower_wages * minimum_wage
+    } else {
+        0.0
+    };
+    let cass = cass_base * tax_rates.health_insurance;
+
+    let income_tax: f64 = categories.iter().map(|result| result.income_tax).sum();
+    let withheld_tax: f64 = categories
+        .iter()
+        .filter(|result| result.withheld_at_source)
+        .map(|result| result.income_tax)
+        .sum();
+    Ok(OtherIncomeResults {
+        year,
+        categories,
+        taxable_income,
+        cass_base,
+        cass,
+        income_tax,
+        withheld_tax,
+        declaration_total: cass + income_tax - withheld_tax,
+    })
+}
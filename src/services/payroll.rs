@@ -0,0 +1,54 @@
+use crate::database::db::TaxRateError;
+use crate::database::db_backup::get_current_year;
+use crate::models::payroll::{EmployeePayslip, PayrollInput, PayrollResults, PayrollTotals};
+use crate::services::calculations::calculate_with_connection;
+use rusqlite::Connection;
+use std::collections::BTreeMap;
+
+// Computes the payslip of every employee for the same month, and the totals the employer
+// declares for the whole team and for each sector.
+pub async fn perform_payroll_calculation(
+    input: PayrollInput,
+) -> Result<PayrollResults, TaxRateError> {
+    let year = input.year.unwrap_or_else(get_current_year);
+    let conn = Connection::open("./tax_rates.db")
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+    let mut employees = Vec::new();
+    let mut totals = PayrollTotals::default();
+    let mut sectors: BTreeMap<String, PayrollTotals> = BTreeMap::new();
+
+    for (index, employee) in input.employees.into_iter().enumerate() {
+        let mut salary = employee.salary;
+        salary.year = Some(year);
+        salary.month = input.month;
+        let (payslip, _) = calculate_with_connection(&conn, salary)
+            .map_err(|error| error.within(&format!("employees[{index}]")))?;
+        // Nothing more than the net salary can be withheld.
+        let deductions = employee.deductions.min(payslip.net_income);
+        let employee = EmployeePayslip {
+            name: employee.name,
+            cnp: employee.cnp,
+            sector: employee.sector,
+            deductions,
+            paid_net: payslip.net_income - deductions,
+            payslip,
+        };
+        totals.add(&employee);
+        if let Some(ref sector) = employee.sector {
+            sectors.entry(sector.clone()).or_default().add(&employee);
+        }
+        employees.push(employee);
+    }
+
+    Ok(PayrollResults {
+        year,
+        month: input.month,
+        employees,
+        totals: totals.apply_rounding(2),
+        sectors: sectors
+            .into_iter()
+            .map(|(sector, totals)| (sector, totals.apply_rounding(2)))
+            .collect(),
+        labels: BTreeMap::new(),
+    })
+}
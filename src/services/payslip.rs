@@ -0,0 +1,69 @@
+use crate::database::db::TaxRates;
+use crate::models::calculations::CalculationInput;
+use crate::models::payslip::{IncomeComponentResult, IncomeComponentType};
+
+// Splits the month's brute income into its components, each taxed like salary income.
+// The overtime is paid relative to the hourly rate of the base salary.
+pub fn calculate_income_components(
+    base_brute_income: f64,
+    input: &CalculationInput,
+    tax_rates: &TaxRates,
+) -> Vec<IncomeComponentResult> {
+    let hourly_rate = base_brute_income / input.working_hours;
+
+    let mut components = vec![calculate_income_component(
+        IncomeComponentType::BaseSalary,
+        None,
+        base_brute_income,
+        tax_rates,
+    )];
+    for bonus in &input.bonuses {
+        components.push(calculate_income_component(
+            IncomeComponentType::Bonus,
+            bonus.name.clone(),
+            bonus.amount,
+            tax_rates,
+        ));
+    }
+    for overtime in &input.overtime {
+        components.push(calculate_income_component(
+            IncomeComponentType::Overtime,
+            Some(format!("{}h x {}", overtime.hours, overtime.multiplier)),
+            hourly_rate * overtime.hours * overtime.multiplier,
+            tax_rates,
+        ));
+    }
+    for payment in &input.one_off_payments {
+        components.push(calculate_income_component(
+            IncomeComponentType::OneOffPayment,
+            payment.name.clone(),
+            payment.amount,
+            tax_rates,
+        ));
+    }
+
+    components
+}
+
+fn calculate_income_component(
+    component_type: IncomeComponentType,
+    name: Option<String>,
+    brute_income: f64,
+    tax_rates: &TaxRates,
+) -> IncomeComponentResult {
+    let cas = brute_income * tax_rates.social_security;
+    let cass = brute_income * tax_rates.health_insurance;
+    let taxable_income = brute_income - cas - cass;
+    let income_tax = taxable_income * tax_rates.income_tax;
+
+    IncomeComponentResult {
+        component_type: component_type.as_str(),
+        name,
+        brute_income,
+        cas,
+        cass,
+        income_tax,
+        net_income: taxable_income - income_tax,
+        cam: brute_income * tax_rates.insurance_contribution,
+    }
+}
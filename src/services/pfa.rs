@@ -0,0 +1,63 @@
+use crate::database::db::{PfaThresholds, TaxRates};
+use crate::models::calculations::CalculationResults;
+use std::collections::BTreeMap;
+
+// Computes the yearly contributions and income tax of a PFA taxed in the real system, on the
+// yearly income minus the deductible expenses.
+pub fn calculate_pfa(
+    income: f64,
+    expenses: f64,
+    tax_rates: &TaxRates,
+    thresholds: &PfaThresholds,
+) -> CalculationResults {
+    let net_income_base = (income - expenses).max(0.0);
+    let minimum_wage = thresholds.minimum_wage;
+
+    // CAS is due on a fixed base, 12 or 24 minimum wages depending on the threshold reached.
+    let cas_base = if net_income_base >= thresholds.cas_upper_wages * minimum_wage {
+        thresholds.cas_upper_wages * minimum_wage
+    } else if net_income_base >= thresholds.cas_lower_wages * minimum_wage {
+        thresholds.cas_lower_wages * minimum_wage
+    } else {
+        0.0
+    };
+    let cas = cas_base * tax_rates.social_security;
+
+    // CASS is due on the net income, at least on the minimum and at most on the cap.
+    let cass_base = net_income_base.clamp(
+        thresholds.cass_min_wages * minimum_wage,
+        thresholds.cass_max_wages * minimum_wage,
+    );
+    let cass = cass_base * tax_rates.health_insurance;
+
+    // The contributions are deducted from the income tax base.
+    let income_tax = (net_income_base - cas - cass).max(0.0) * tax_rates.income_tax;
+    let taxes = cas + cass + income_tax;
+    let net_income = net_income_base - taxes;
+    let share = |amount: f64| {
+        if income > 0.0 {
+            amount * 100f64 / income
+        } else {
+            0.0
+        }
+    };
+
+    CalculationResults {
+        brute_income: income,
+        net_income,
+        cas,
+        cass,
+        income_tax,
+        cam: 0.0,
+        total_salary: income,
+        employee_tax_percentage: share(net_income),
+        state_tax_percentage: share(taxes),
+        benefits: Vec::new(),
+        benefits_value: 0.0,
+        take_home: net_income,
+        expenses,
+        exemption: None,
+        income_components: Vec::new(),
+        labels: BTreeMap::new(),
+    }
+}
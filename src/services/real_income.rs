@@ -0,0 +1,49 @@
+use crate::database::db::{get_economic_indices, TaxRateError};
+use crate::database::db_backup::get_current_year;
+use crate::models::real_income::{RealIncomeInput, RealIncomeResults};
+use crate::services::calculations::perform_calculation;
+use crate::utils::round_to;
+use rusqlite::Connection;
+use std::collections::BTreeMap;
+
+// Computes both salaries with the rates of their year, then restates the first one with the
+// consumer price index of the second year.
+pub async fn perform_real_income_comparison(
+    input: RealIncomeInput,
+) -> Result<RealIncomeResults, TaxRateError> {
+    let conn = Connection::open("./tax_rates.db")
+        .map_err(|error| TaxRateError::DatabaseError(error.to_string()))?;
+    let from_year = input.from.year.unwrap_or_else(get_current_year);
+    let to_year = input.to.year.unwrap_or_else(get_current_year);
+    let from_indices = get_economic_indices(&conn, from_year)?;
+    let to_indices = get_economic_indices(&conn, to_year)?;
+    let inflation_factor = to_indices.cpi / from_indices.cpi;
+    let wage_factor = to_indices.average_gross_wage / from_indices.average_gross_wage;
+
+    let (from, _) = perform_calculation(input.from)
+        .await
+        .map_err(|error| error.within("from"))?;
+    let (to, _) = perform_calculation(input.to)
+        .await
+        .map_err(|error| error.within("to"))?;
+    let restated = from.restate(inflation_factor).apply_rounding(2);
+
+    let real_net_change = to.net_income - restated.net_income;
+    Ok(RealIncomeResults {
+        from_year,
+        to_year,
+        inflation_factor: round_to(inflation_factor, 4),
+        wage_factor: round_to(wage_factor, 4),
+        real_net_change: round_to(real_net_change, 2),
+        real_net_change_percentage: if restated.net_income > 0.0 {
+            round_to(real_net_change * 100f64 / restated.net_income, 2)
+        } else {
+            0.0
+        },
+        wage_indexed_net_income: round_to(from.net_income * wage_factor, 2),
+        from,
+        restated,
+        to,
+        labels: BTreeMap::new(),
+    })
+}
@@ -2,3 +2,122 @@ pub fn round_to(num: f64, decimals: i32) -> f64 {
     let factor = 10f64.powi(decimals);
     (num * factor).round() / factor
 }
+
+// Parses a non-negative amount such as an income, a price or a number of hours.
+// Both "4.500,50" and "4,500.50" are accepted: when the two separators appear the last
+// one is the decimal separator, and a single separator followed by exactly three digits
+// is taken as a thousands separator when it follows 1 to 3 digits without a leading zero,
+// so "4.500" is 4500 but "0.500" is 0.5.
+pub fn parse_amount(value: &str) -> Option<f64> {
+    let value: String = value
+        .trim()
+        .chars()
+        .filter(|char| !matches!(char, ' ' | '\u{a0}' | '\u{202f}' | '_'))
+        .collect();
+    let last_dot = value.rfind('.');
+    let last_comma = value.rfind(',');
+    let decimal_separator = match (last_dot, last_comma) {
+        (Some(dot), Some(comma)) => Some(if dot > comma { '.' } else { ',' }),
+        (Some(_), None) => decimal_separator_candidate(&value, '.'),
+        (None, Some(_)) => decimal_separator_candidate(&value, ','),
+        (None, None) => None,
+    };
+
+    let (integer_part, decimal_part) = match decimal_separator {
+        Some(separator) => value.rsplit_once(separator)?,
+        None => (value.as_str(), ""),
+    };
+    if decimal_part.contains(['.', ',']) {
+        return None;
+    }
+    let groups: Vec<&str> = integer_part.split(['.', ',']).collect();
+    let is_grouped = groups.len() > 1;
+    if is_grouped
+        && (!is_leading_thousands_group(groups[0])
+            || groups[1..].iter().any(|group| group.len() != 3))
+    {
+        return None;
+    }
+
+    let normalized = format!("{}.{}", groups.concat(), decimal_part);
+    if !normalized
+        .chars()
+        .all(|char| char.is_ascii_digit() || char == '.')
+        || normalized == "."
+    {
+        return None;
+    }
+    match normalized.trim_end_matches('.').parse::<f64>() {
+        Ok(amount) if amount.is_finite() => Some(amount),
+        _ => None,
+    }
+}
+
+fn decimal_separator_candidate(value: &str, separator: char) -> Option<char> {
+    let (leading, decimals) = value.rsplit_once(separator)?;
+    if value.matches(separator).count() > 1
+        || (decimals.len() == 3 && is_leading_thousands_group(leading))
+    {
+        // A repeated separator or three digits after a first group of thousands.
+        None
+    } else {
+        Some(separator)
+    }
+}
+
+fn is_leading_thousands_group(group: &str) -> bool {
+    (1..=3).contains(&group.len()) && !group.starts_with('0')
+}
+
+// Renames every object key of a JSON value from camelCase to snake_case.
+pub fn to_snake_case_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| (to_snake_case(&key), to_snake_case_keys(value)))
+            .collect(),
+        serde_json::Value::Array(values) => values.into_iter().map(to_snake_case_keys).collect(),
+        value => value,
+    }
+}
+
+fn to_snake_case(key: &str) -> String {
+    let mut snake_case = String::with_capacity(key.len());
+    for char in key.chars() {
+        if char.is_ascii_uppercase() {
+            snake_case.push('_');
+            snake_case.push(char.to_ascii_lowercase());
+        } else {
+            snake_case.push(char);
+        }
+    }
+    snake_case
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_amount;
+
+    #[test]
+    fn parse_amount_groups_thousands() {
+        assert_eq!(parse_amount("4.500"), Some(4500.0));
+        assert_eq!(parse_amount("4,500"), Some(4500.0));
+        assert_eq!(parse_amount("1.234.567,89"), Some(1234567.89));
+        assert_eq!(parse_amount("4.500,50"), Some(4500.5));
+        assert_eq!(parse_amount("4,500.50"), Some(4500.5));
+    }
+
+    #[test]
+    fn parse_amount_reads_a_leading_zero_as_decimals() {
+        assert_eq!(parse_amount("0.500"), Some(0.5));
+        assert_eq!(parse_amount("0,125"), Some(0.125));
+        assert_eq!(parse_amount("00.123"), Some(0.123));
+    }
+
+    #[test]
+    fn parse_amount_rejects_malformed_groups() {
+        assert_eq!(parse_amount("4.50.0"), None);
+        assert_eq!(parse_amount("0.500.000"), None);
+        assert_eq!(parse_amount(".500.000"), None);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::models::calculations::NumberOrString;
+use crate::models::validation::ValidationError;
+use crate::utils::parse_amount;
+
+// Incomes above this are most likely typos, the bound leaves room for annual figures.
+pub const MAX_AMOUNT: f64 = 100_000_000.0;
+
+pub fn validate_amount(
+    data: Option<&NumberOrString>,
+    field: &str,
+    label: &'static str,
+) -> Result<f64, ValidationError> {
+    let amount = match data {
+        None => return Err(ValidationError::new(field, "missing", label, None)),
+        Some(NumberOrString::String(value)) if value.trim().is_empty() => {
+            return Err(ValidationError::new(field, "missing", label, None));
+        }
+        Some(NumberOrString::Number(value)) if *value >= 0.0 => *value,
+        Some(NumberOrString::Number(value)) => {
+            return Err(ValidationError::new(
+                field,
+                "negative_amount",
+                label,
+                Some(value.to_string()),
+            ));
+        }
+        Some(NumberOrString::String(value)) => match parse_amount(value) {
+            Some(output) => output,
+            None => {
+                return Err(ValidationError::new(
+                    field,
+                    "invalid_amount",
+                    label,
+                    Some(format!("{value:?}")),
+                ));
+            }
+        },
+    };
+
+    if amount > MAX_AMOUNT {
+        return Err(ValidationError::new(
+            field,
+            "amount_too_large",
+            label,
+            Some(amount.to_string()),
+        ));
+    }
+    Ok(amount)
+}
+
+// Parses an optional number, an empty value falls back to the default.
+pub fn validate_optional_number(
+    data: Option<&str>,
+    default: f64,
+    field: &str,
+    code: &'static str,
+    label: &'static str,
+) -> Result<f64, ValidationError> {
+    match data.map(str::trim) {
+        None | Some("") => Ok(default),
+        Some(value) => parse_amount(value)
+            .ok_or_else(|| ValidationError::new(field, code, label, Some(format!("{value:?}")))),
+    }
+}
@@ -0,0 +1,63 @@
+use crate::models::annual::{AnnualInput, AnnualSchema, MONTHS_PER_YEAR};
+use crate::models::calculations::IncomeType;
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::validators::calculations::{validate_calculate_input, validate_optional_integer};
+
+pub fn validate_annual_input(data: &AnnualSchema) -> Result<AnnualInput, ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    let year = report.check(validate_optional_integer(
+        data.year.as_deref(),
+        "year",
+        "invalid_year",
+        "year",
+    ));
+    let months = data.months.as_deref().unwrap_or_default();
+    if months.len() != MONTHS_PER_YEAR {
+        report.push(ValidationError::new(
+            "months",
+            "invalid_month_count",
+            "months",
+            Some(months.len().to_string()),
+        ));
+    }
+
+    // Each month is validated like a single calculation, its errors point to the month.
+    let mut inputs = Vec::new();
+    for (index, month) in months.iter().enumerate() {
+        // The year is given once for the whole calculation, the months can't change it.
+        if month.year.is_some() {
+            report.push(ValidationError::new(
+                format!("months[{index}].year"),
+                "set_for_the_whole_request",
+                "year",
+                None,
+            ));
+        }
+        match validate_calculate_input(month) {
+            Ok(input) if input.income_type == IncomeType::PFA => {
+                report.push(ValidationError::new(
+                    format!("months[{index}].incomeType"),
+                    "unsupported_income_type",
+                    "income_type",
+                    Some(String::from("\"PFA\"")),
+                ));
+            }
+            Ok(input) => inputs.push(input),
+            Err(month_report) => {
+                for mut error in month_report.errors {
+                    error.field = format!("months[{index}].{}", error.field);
+                    report.push(error);
+                }
+            }
+        }
+    }
+
+    match year {
+        Some(year) if report.is_empty() => Ok(AnnualInput {
+            year,
+            months: inputs,
+        }),
+        _ => Err(report),
+    }
+}
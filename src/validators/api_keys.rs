@@ -0,0 +1,56 @@
+use crate::models::api_keys::{
+    ApiKeyInput, ApiKeySchema, DEFAULT_DAILY_QUOTA, DEFAULT_REQUESTS_PER_MINUTE,
+};
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::validators::calculations::validate_optional_integer;
+
+pub fn validate_api_key_input(data: &ApiKeySchema) -> Result<ApiKeyInput, ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    let name = report.check(
+        data.name
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .ok_or_else(|| ValidationError::new("name", "missing", "api_key_name", None)),
+    );
+    let daily_quota = report.check(validate_limit(
+        data.daily_quota.as_deref(),
+        "dailyQuota",
+        "daily_quota",
+    ));
+    let requests_per_minute = report.check(validate_limit(
+        data.requests_per_minute.as_deref(),
+        "requestsPerMinute",
+        "requests_per_minute",
+    ));
+
+    match (name, daily_quota, requests_per_minute) {
+        (Some(name), Some(daily_quota), Some(requests_per_minute)) if report.is_empty() => {
+            Ok(ApiKeyInput {
+                name,
+                daily_quota: daily_quota.unwrap_or(DEFAULT_DAILY_QUOTA),
+                requests_per_minute: requests_per_minute.unwrap_or(DEFAULT_REQUESTS_PER_MINUTE),
+            })
+        }
+        _ => Err(report),
+    }
+}
+
+// A limit of 0 would make the key unusable, so it has to be positive.
+fn validate_limit(
+    data: Option<&str>,
+    field: &str,
+    label: &'static str,
+) -> Result<Option<u32>, ValidationError> {
+    match validate_optional_integer(data, field, "invalid_limit", label)? {
+        Some(0) => Err(ValidationError::new(
+            field,
+            "invalid_limit",
+            label,
+            Some(String::from("\"0\"")),
+        )),
+        limit => Ok(limit),
+    }
+}
@@ -0,0 +1,71 @@
+use crate::models::benefits::{Benefit, BenefitSchema, BenefitType};
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::validators::amounts::{validate_amount, validate_optional_number};
+
+pub fn validate_benefits(
+    data: Option<&Vec<BenefitSchema>>,
+    report: &mut ValidationReport,
+) -> Vec<Benefit> {
+    let mut benefits = Vec::new();
+    for (index, benefit) in data
+        .map(|benefits| benefits.iter())
+        .into_iter()
+        .flatten()
+        .enumerate()
+    {
+        let benefit_type = report.check(
+            benefit
+                .benefit_type
+                .as_deref()
+                .and_then(BenefitType::from_str)
+                .ok_or_else(|| {
+                    ValidationError::new(
+                        format!("benefits[{index}].type"),
+                        "unsupported_benefit_type",
+                        "benefit_type",
+                        Some(format!(
+                            "{:?}",
+                            benefit.benefit_type.as_deref().unwrap_or_default()
+                        )),
+                    )
+                }),
+        );
+        let amount = report.check(validate_amount(
+            benefit.amount.as_ref(),
+            &format!("benefits[{index}].amount"),
+            "benefit_amount",
+        ));
+        // A single benefit is assumed when the count is not given.
+        let count = report.check(
+            validate_optional_number(
+                benefit.count.as_deref(),
+                1.0,
+                &format!("benefits[{index}].count"),
+                "invalid_count",
+                "benefit_count",
+            )
+            .and_then(|count| {
+                if count.fract() == 0.0 && count <= u32::MAX as f64 {
+                    Ok(count as u32)
+                } else {
+                    Err(ValidationError::new(
+                        format!("benefits[{index}].count"),
+                        "invalid_count",
+                        "benefit_count",
+                        Some(format!("\"{count}\"")),
+                    ))
+                }
+            }),
+        );
+
+        if let (Some(benefit_type), Some(amount), Some(count)) = (benefit_type, amount, count) {
+            benefits.push(Benefit {
+                benefit_type,
+                amount,
+                count,
+            });
+        }
+    }
+
+    benefits
+}
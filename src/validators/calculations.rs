@@ -1,44 +1,206 @@
+use chrono::{Local, NaiveDate};
+
 use crate::models::calculations::{CalculateSchema, CalculationInput, Currency, IncomeType};
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::validators::amounts::validate_amount;
+use crate::validators::benefits::validate_benefits;
+use crate::validators::payslip::{validate_overtime, validate_payments, validate_working_hours};
 
-pub fn validate_calculate_input(data: &CalculateSchema) -> Result<CalculationInput, String> {
-    let income: u32 = match data.income.as_deref().unwrap_or("").trim().parse() {
-        Ok(output) => output,
-        Err(_) => {
-            return Err(String::from("Invalid or missing income."));
-        }
-    };
-    let income_type = match data.income_type.as_deref().and_then(IncomeType::from_str) {
-        Some(income_type) => income_type,
-        None => {
-            return Err(format!(
-                "Unsupported income type {:?}.",
-                data.income_type.as_deref().unwrap_or_default()
-            ));
-        }
+pub fn validate_calculate_input(
+    data: &CalculateSchema,
+) -> Result<CalculationInput, ValidationReport> {
+    // Every field is validated so that all the problems are reported at once.
+    let mut report = ValidationReport::default();
+
+    let income = report.check(validate_amount(data.income.as_ref(), "income", "income"));
+    let income_type = report.check(
+        data.income_type
+            .as_deref()
+            .and_then(IncomeType::from_str)
+            .ok_or_else(|| {
+                ValidationError::new(
+                    "incomeType",
+                    "unsupported_income_type",
+                    "income_type",
+                    Some(format!(
+                        "{:?}",
+                        data.income_type.as_deref().unwrap_or_default()
+                    )),
+                )
+            }),
+    );
+    let currency = report.check(
+        data.currency
+            .as_deref()
+            .and_then(Currency::from_str)
+            .ok_or_else(|| {
+                ValidationError::new(
+                    "currency",
+                    "unsupported_currency",
+                    "currency",
+                    Some(format!(
+                        "{:?}",
+                        data.currency.as_deref().unwrap_or_default()
+                    )),
+                )
+            }),
+    );
+    let custom_tax = report.check(validate_optional_integer(
+        data.custom_tax.as_deref(),
+        "customTax",
+        "invalid_custom_tax",
+        "custom_tax",
+    ));
+    let year = report.check(validate_optional_integer(
+        data.year.as_deref(),
+        "year",
+        "invalid_year",
+        "year",
+    ));
+    let benefits = validate_benefits(data.benefits.as_ref(), &mut report);
+    let bonuses = validate_payments(
+        data.bonuses.as_ref(),
+        "bonuses",
+        "bonus_amount",
+        &mut report,
+    );
+    let overtime = validate_overtime(data.overtime.as_ref(), &mut report);
+    let one_off_payments = validate_payments(
+        data.one_off_payments.as_ref(),
+        "oneOffPayments",
+        "one_off_payment_amount",
+        &mut report,
+    );
+    let working_hours = report.check(validate_working_hours(data.working_hours.as_deref()));
+    let birth_date = report.check(validate_birth_date(data.birth_date.as_deref()));
+    let expenses = match data.expenses {
+        None => Some(0.0),
+        Some(ref expenses) => report.check(validate_amount(Some(expenses), "expenses", "expenses")),
     };
-    let currency = match data.currency.as_deref().and_then(Currency::from_str) {
-        Some(currency) => currency,
-        None => {
-            return Err(format!(
-                "Currency {:?} not supported.",
-                data.currency.as_deref().unwrap_or_default()
-            ));
+
+    // The payslip components and the income tax exemptions only exist for employees, and
+    // the expenses only for the self-employed.
+    if let Some(ref income_type) = income_type {
+        let is_pfa = *income_type == IncomeType::PFA;
+        for (field, label, given, allowed) in [
+            (
+                "benefits",
+                "benefits",
+                data.benefits.as_ref().is_some_and(|list| !list.is_empty()),
+                !is_pfa,
+            ),
+            (
+                "bonuses",
+                "bonuses",
+                data.bonuses.as_ref().is_some_and(|list| !list.is_empty()),
+                !is_pfa,
+            ),
+            (
+                "overtime",
+                "overtime",
+                data.overtime.as_ref().is_some_and(|list| !list.is_empty()),
+                !is_pfa,
+            ),
+            (
+                "oneOffPayments",
+                "one_off_payments",
+                data.one_off_payments
+                    .as_ref()
+                    .is_some_and(|list| !list.is_empty()),
+                !is_pfa,
+            ),
+            (
+                "birthDate",
+                "birth_date",
+                data.birth_date.is_some(),
+                !is_pfa,
+            ),
+            (
+                "disability",
+                "disability",
+                data.disability.unwrap_or(false),
+                !is_pfa,
+            ),
+            ("expenses", "expenses", data.expenses.is_some(), is_pfa),
+        ] {
+            if given && !allowed {
+                report.push(ValidationError::new(
+                    field,
+                    "unsupported_for_income_type",
+                    label,
+                    Some(format!("{income_type:?}")),
+                ));
+            }
         }
-    };
-    let custom_tax: Option<u32> = data
-        .custom_tax
-        .as_deref()
-        .get_or_insert("")
-        .trim()
-        .parse()
-        .ok();
-    let year: Option<u32> = data.year.as_deref().unwrap_or("").trim().parse().ok();
+    }
 
-    Ok(CalculationInput {
+    match (
         income,
         income_type,
         currency,
         custom_tax,
         year,
-    })
+        working_hours,
+        expenses,
+    ) {
+        (
+            Some(income),
+            Some(income_type),
+            Some(currency),
+            Some(custom_tax),
+            Some(year),
+            Some(working_hours),
+            Some(expenses),
+        ) if report.is_empty() => Ok(CalculationInput {
+            income,
+            income_type,
+            currency,
+            custom_tax,
+            year,
+            month: None,
+            benefits,
+            bonuses,
+            overtime,
+            one_off_payments,
+            working_hours,
+            expenses,
+            birth_date: birth_date.flatten(),
+            disability: data.disability.unwrap_or(false),
+        }),
+        _ => Err(report),
+    }
+}
+
+// Birth dates are given as YYYY-MM-DD and cannot be in the future.
+fn validate_birth_date(data: Option<&str>) -> Result<Option<NaiveDate>, ValidationError> {
+    match data.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .filter(|date| *date <= Local::now().date_naive())
+            .map(Some)
+            .ok_or_else(|| {
+                ValidationError::new(
+                    "birthDate",
+                    "invalid_birth_date",
+                    "birth_date",
+                    Some(format!("{value:?}")),
+                )
+            }),
+    }
+}
+
+pub(crate) fn validate_optional_integer(
+    data: Option<&str>,
+    field: &str,
+    code: &'static str,
+    label: &'static str,
+) -> Result<Option<u32>, ValidationError> {
+    match data.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) => value
+            .parse()
+            .map(Some)
+            .map_err(|_| ValidationError::new(field, code, label, Some(format!("{value:?}")))),
+    }
 }
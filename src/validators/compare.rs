@@ -0,0 +1,29 @@
+use crate::models::compare::{CompareInput, CompareSchema};
+use crate::models::validation::ValidationReport;
+use crate::validators::amounts::validate_amount;
+use crate::validators::calculations::validate_optional_integer;
+
+pub fn validate_compare_input(data: &CompareSchema) -> Result<CompareInput, ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    let budget = report.check(validate_amount(data.budget.as_ref(), "budget", "budget"));
+    let expenses = match data.expenses {
+        None => Some(0.0),
+        Some(ref expenses) => report.check(validate_amount(Some(expenses), "expenses", "expenses")),
+    };
+    let year = report.check(validate_optional_integer(
+        data.year.as_deref(),
+        "year",
+        "invalid_year",
+        "year",
+    ));
+
+    match (budget, expenses, year) {
+        (Some(budget), Some(expenses), Some(year)) if report.is_empty() => Ok(CompareInput {
+            budget,
+            expenses,
+            year,
+        }),
+        _ => Err(report),
+    }
+}
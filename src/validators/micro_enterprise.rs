@@ -0,0 +1,33 @@
+use crate::models::micro_enterprise::{MicroEnterpriseInput, MicroEnterpriseSchema};
+use crate::models::validation::ValidationReport;
+use crate::validators::amounts::validate_amount;
+use crate::validators::calculations::validate_optional_integer;
+
+pub fn validate_micro_enterprise_input(
+    data: &MicroEnterpriseSchema,
+) -> Result<MicroEnterpriseInput, ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    let revenue = report.check(validate_amount(data.revenue.as_ref(), "revenue", "revenue"));
+    let expenses = match data.expenses {
+        None => Some(0.0),
+        Some(ref expenses) => report.check(validate_amount(Some(expenses), "expenses", "expenses")),
+    };
+    let year = report.check(validate_optional_integer(
+        data.year.as_deref(),
+        "year",
+        "invalid_year",
+        "year",
+    ));
+
+    match (revenue, expenses, year) {
+        (Some(revenue), Some(expenses), Some(year)) if report.is_empty() => {
+            Ok(MicroEnterpriseInput {
+                revenue,
+                expenses,
+                year,
+            })
+        }
+        _ => Err(report),
+    }
+}
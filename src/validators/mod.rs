@@ -1 +1,11 @@
+pub mod amounts;
+pub mod annual;
+pub mod api_keys;
+pub mod benefits;
 pub mod calculations;
+pub mod compare;
+pub mod micro_enterprise;
+pub mod other_income;
+pub mod payroll;
+pub mod payslip;
+pub mod real_income;
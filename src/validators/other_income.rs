@@ -0,0 +1,57 @@
+use crate::models::other_income::{
+    OtherIncomeCategory, OtherIncomeInput, OtherIncomeSchema, OtherIncomeSource,
+};
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::validators::amounts::validate_amount;
+use crate::validators::calculations::validate_optional_integer;
+
+pub fn validate_other_income_input(
+    data: &OtherIncomeSchema,
+) -> Result<OtherIncomeInput, ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    let sources = data.incomes.as_deref().unwrap_or_default();
+    if sources.is_empty() {
+        report.push(ValidationError::new("incomes", "missing", "incomes", None));
+    }
+    let mut incomes = Vec::new();
+    for (index, source) in sources.iter().enumerate() {
+        let category = report.check(
+            source
+                .category
+                .as_deref()
+                .and_then(OtherIncomeCategory::from_str)
+                .ok_or_else(|| {
+                    ValidationError::new(
+                        format!("incomes[{index}].category"),
+                        "unsupported_income_category",
+                        "income_category",
+                        Some(format!(
+                            "{:?}",
+                            source.category.as_deref().unwrap_or_default()
+                        )),
+                    )
+                }),
+        );
+        let amount = report.check(validate_amount(
+            source.amount.as_ref(),
+            &format!("incomes[{index}].amount"),
+            "income",
+        ));
+
+        if let (Some(category), Some(amount)) = (category, amount) {
+            incomes.push(OtherIncomeSource { category, amount });
+        }
+    }
+    let year = report.check(validate_optional_integer(
+        data.year.as_deref(),
+        "year",
+        "invalid_year",
+        "year",
+    ));
+
+    match year {
+        Some(year) if report.is_empty() => Ok(OtherIncomeInput { incomes, year }),
+        _ => Err(report),
+    }
+}
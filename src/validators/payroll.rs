@@ -0,0 +1,221 @@
+use crate::models::calculations::IncomeType;
+use crate::models::payroll::{D112Input, EmployeeInput, Employer, PayrollInput, PayrollSchema};
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::validators::amounts::validate_amount;
+use crate::validators::calculations::{validate_calculate_input, validate_optional_integer};
+
+pub fn validate_payroll_input(data: &PayrollSchema) -> Result<PayrollInput, ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    let year = report.check(validate_optional_integer(
+        data.year.as_deref(),
+        "year",
+        "invalid_year",
+        "year",
+    ));
+    let month = report.check(
+        validate_optional_integer(data.month.as_deref(), "month", "invalid_month", "month")
+            .and_then(|month| match month {
+                Some(0) | Some(13..) => Err(ValidationError::new(
+                    "month",
+                    "invalid_month",
+                    "month",
+                    month.map(|month| format!("\"{month}\"")),
+                )),
+                month => Ok(month),
+            }),
+    );
+    let employees = data.employees.as_deref().unwrap_or_default();
+    if employees.is_empty() {
+        report.push(ValidationError::new(
+            "employees",
+            "missing",
+            "employees",
+            None,
+        ));
+    }
+
+    // Each salary is validated like a single calculation, its errors point to the employee.
+    let mut inputs = Vec::new();
+    for (index, employee) in employees.iter().enumerate() {
+        // The year and month are given once for the whole payroll, an employee can't change
+        // them.
+        for (field, given) in [
+            ("year", employee.salary.year.is_some()),
+            ("month", employee.month.is_some()),
+        ] {
+            if given {
+                report.push(ValidationError::new(
+                    format!("employees[{index}].{field}"),
+                    "set_for_the_whole_request",
+                    field,
+                    None,
+                ));
+            }
+        }
+        let name = report.check(
+            employee
+                .name
+                .as_deref()
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    ValidationError::new(
+                        format!("employees[{index}].name"),
+                        "missing",
+                        "employee_name",
+                        None,
+                    )
+                }),
+        );
+        let deductions = match employee.deductions {
+            None => Some(0.0),
+            Some(ref deductions) => report.check(validate_amount(
+                Some(deductions),
+                &format!("employees[{index}].deductions"),
+                "deductions",
+            )),
+        };
+        let salary = match validate_calculate_input(&employee.salary) {
+            Ok(input) if input.income_type == IncomeType::PFA => {
+                report.push(ValidationError::new(
+                    format!("employees[{index}].incomeType"),
+                    "unsupported_income_type",
+                    "income_type",
+                    Some(String::from("\"PFA\"")),
+                ));
+                None
+            }
+            Ok(input) => Some(input),
+            Err(salary_report) => {
+                for mut error in salary_report.errors {
+                    error.field = format!("employees[{index}].{}", error.field);
+                    report.push(error);
+                }
+                None
+            }
+        };
+
+        if let (Some(name), Some(deductions), Some(salary)) = (name, deductions, salary) {
+            inputs.push(EmployeeInput {
+                name,
+                cnp: employee.cnp.as_deref().map(str::trim).map(str::to_string),
+                sector: employee
+                    .sector
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|sector| !sector.is_empty())
+                    .map(str::to_string),
+                deductions,
+                salary,
+            });
+        }
+    }
+
+    match (year, month) {
+        (Some(year), Some(month)) if report.is_empty() => Ok(PayrollInput {
+            year,
+            month,
+            employees: inputs,
+        }),
+        _ => Err(report),
+    }
+}
+
+// The D112 return also needs the employer, the month and the code of every employee. These
+// are checked on top of the payroll so that all the problems are reported at once.
+pub fn validate_d112_input(data: &PayrollSchema) -> Result<D112Input, ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    let employer = data.employer.as_ref();
+    let cif = report.check(
+        employer
+            .and_then(|employer| employer.cif.as_deref())
+            .map(|cif| cif.trim().trim_start_matches("RO"))
+            .filter(|cif| (2..=10).contains(&cif.len()) && cif.chars().all(|c| c.is_ascii_digit()))
+            .map(str::to_string)
+            .ok_or_else(|| {
+                ValidationError::new(
+                    "employer.cif",
+                    "invalid_cif",
+                    "cif",
+                    Some(format!(
+                        "{:?}",
+                        employer
+                            .and_then(|employer| employer.cif.as_deref())
+                            .unwrap_or_default()
+                    )),
+                )
+            }),
+    );
+    let name = report.check(
+        employer
+            .and_then(|employer| employer.name.as_deref())
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .ok_or_else(|| ValidationError::new("employer.name", "missing", "employer_name", None)),
+    );
+    if data
+        .month
+        .as_deref()
+        .map(str::trim)
+        .unwrap_or_default()
+        .is_empty()
+    {
+        report.push(ValidationError::new("month", "missing", "month", None));
+    }
+    for (index, employee) in data.employees.iter().flatten().enumerate() {
+        let cnp = employee.cnp.as_deref().map(str::trim).unwrap_or_default();
+        if !is_valid_cnp(cnp) {
+            report.push(ValidationError::new(
+                format!("employees[{index}].cnp"),
+                "invalid_cnp",
+                "cnp",
+                Some(format!("{cnp:?}")),
+            ));
+        }
+    }
+
+    let payroll = match validate_payroll_input(data) {
+        Ok(payroll) => Some(payroll),
+        Err(payroll_report) => {
+            for error in payroll_report.errors {
+                report.push(error);
+            }
+            None
+        }
+    };
+
+    match (cif, name, payroll) {
+        (Some(cif), Some(name), Some(payroll)) if report.is_empty() => Ok(D112Input {
+            employer: Employer { cif, name },
+            month: payroll.month.unwrap_or_default(),
+            payroll,
+        }),
+        _ => Err(report),
+    }
+}
+
+// The weights of the first 12 digits in the control digit.
+static CNP_WEIGHTS: [u32; 12] = [2, 7, 9, 1, 4, 6, 3, 5, 8, 2, 7, 9];
+
+// 13 digits, the first one giving the gender and century and the last one the control digit:
+// the weighted sum of the others modulo 11, where 10 is written as 1.
+fn is_valid_cnp(cnp: &str) -> bool {
+    let digits: Vec<u32> = cnp.chars().filter_map(|c| c.to_digit(10)).collect();
+    if cnp.len() != 13 || digits.len() != 13 || digits[0] == 0 {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .zip(CNP_WEIGHTS)
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    let control = match sum % 11 {
+        10 => 1,
+        rest => rest,
+    };
+    digits[12] == control
+}
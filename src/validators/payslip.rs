@@ -0,0 +1,98 @@
+use crate::models::payslip::{
+    Overtime, OvertimeSchema, Payment, PaymentSchema, DEFAULT_OVERTIME_MULTIPLIER,
+    DEFAULT_WORKING_HOURS,
+};
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::utils::parse_amount;
+use crate::validators::amounts::{validate_amount, validate_optional_number};
+
+pub fn validate_payments(
+    data: Option<&Vec<PaymentSchema>>,
+    field: &str,
+    label: &'static str,
+    report: &mut ValidationReport,
+) -> Vec<Payment> {
+    let mut payments = Vec::new();
+    for (index, payment) in data
+        .map(|payments| payments.iter())
+        .into_iter()
+        .flatten()
+        .enumerate()
+    {
+        let amount = report.check(validate_amount(
+            payment.amount.as_ref(),
+            &format!("{field}[{index}].amount"),
+            label,
+        ));
+
+        if let Some(amount) = amount {
+            payments.push(Payment {
+                name: payment.name.clone(),
+                amount,
+            });
+        }
+    }
+
+    payments
+}
+
+pub fn validate_overtime(
+    data: Option<&Vec<OvertimeSchema>>,
+    report: &mut ValidationReport,
+) -> Vec<Overtime> {
+    let mut overtime = Vec::new();
+    for (index, entry) in data
+        .map(|overtime| overtime.iter())
+        .into_iter()
+        .flatten()
+        .enumerate()
+    {
+        let hours = report.check(
+            entry
+                .hours
+                .as_deref()
+                .and_then(parse_amount)
+                .ok_or_else(|| {
+                    ValidationError::new(
+                        format!("overtime[{index}].hours"),
+                        "invalid_hours",
+                        "overtime_hours",
+                        None,
+                    )
+                }),
+        );
+        let multiplier = report.check(validate_optional_number(
+            entry.multiplier.as_deref(),
+            DEFAULT_OVERTIME_MULTIPLIER,
+            &format!("overtime[{index}].multiplier"),
+            "invalid_multiplier",
+            "overtime_multiplier",
+        ));
+
+        if let (Some(hours), Some(multiplier)) = (hours, multiplier) {
+            overtime.push(Overtime { hours, multiplier });
+        }
+    }
+
+    overtime
+}
+
+pub fn validate_working_hours(data: Option<&str>) -> Result<f64, ValidationError> {
+    let working_hours = validate_optional_number(
+        data,
+        DEFAULT_WORKING_HOURS,
+        "workingHours",
+        "invalid_working_hours",
+        "working_hours",
+    )?;
+    if working_hours > 0.0 {
+        Ok(working_hours)
+    } else {
+        Err(ValidationError::new(
+            "workingHours",
+            "invalid_working_hours",
+            "working_hours",
+            Some(format!("\"{working_hours}\"")),
+        ))
+    }
+}
@@ -0,0 +1,56 @@
+use crate::models::calculations::{CalculateSchema, CalculationInput};
+use crate::models::real_income::{RealIncomeInput, RealIncomeSchema};
+use crate::models::validation::{ValidationError, ValidationReport};
+use crate::validators::calculations::validate_calculate_input;
+
+pub fn validate_real_income_input(
+    data: &RealIncomeSchema,
+) -> Result<RealIncomeInput, ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    let from = validate_salary(data.from.as_ref(), "from", &mut report);
+    let to = validate_salary(data.to.as_ref(), "to", &mut report);
+
+    match (from, to) {
+        (Some(from), Some(to)) if report.is_empty() => Ok(RealIncomeInput { from, to }),
+        _ => Err(report),
+    }
+}
+
+// Each salary is validated like a single calculation, its errors point to the salary. The year
+// is required, the indices are only known for past years.
+fn validate_salary(
+    data: Option<&CalculateSchema>,
+    field: &str,
+    report: &mut ValidationReport,
+) -> Option<CalculationInput> {
+    let Some(data) = data else {
+        report.push(ValidationError::new(field, "missing", "salary", None));
+        return None;
+    };
+    if data
+        .year
+        .as_deref()
+        .map(str::trim)
+        .unwrap_or_default()
+        .is_empty()
+    {
+        report.push(ValidationError::new(
+            format!("{field}.year"),
+            "missing",
+            "year",
+            None,
+        ));
+    }
+    match validate_calculate_input(data) {
+        Ok(input) if input.year.is_some() => Some(input),
+        Ok(_) => None,
+        Err(salary_report) => {
+            for mut error in salary_report.errors {
+                error.field = format!("{field}.{}", error.field);
+                report.push(error);
+            }
+            None
+        }
+    }
+}
@@ -1 +1,54 @@
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
 pub const LOCALHOST: &str = "http://localhost:8000";
+
+// A scratch directory so a spawned server gets its own database.
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("calven-{name}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("The scratch directory should be created");
+    dir
+}
+
+// A second server started with its own configuration, killed when dropped.
+pub struct SpawnedServer {
+    pub child: Child,
+    pub url: String,
+}
+
+impl SpawnedServer {
+    pub async fn start(name: &str, port: u16, envs: &[(&str, &str)]) -> SpawnedServer {
+        let child = Command::new(env!("CARGO_BIN_EXE_calven"))
+            .current_dir(scratch_dir(name))
+            .env("SERVER_ADDRESS", format!("127.0.0.1:{port}"))
+            .envs(envs.iter().copied())
+            .stdout(Stdio::null())
+            .spawn()
+            .expect("The server should spawn");
+        let server = SpawnedServer {
+            child,
+            url: format!("http://127.0.0.1:{port}"),
+        };
+
+        for _ in 0..50 {
+            if reqwest::get(format!("{}/health/live", server.url))
+                .await
+                .is_ok()
+            {
+                return server;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("The server on port {port} should start");
+    }
+}
+
+impl Drop for SpawnedServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{scratch_dir, SpawnedServer, LOCALHOST};
+use rusqlite::Connection;
+use serde_json::{json, Value};
+
+fn months(christmas_bonus: bool) -> Vec<Value> {
+    (1..=12)
+        .map(|month| {
+            let mut data = json!({"income": "10000", "incomeType": "BRUTE", "currency": "RON"});
+            if christmas_bonus && month == 12 {
+                data["bonuses"] = json!([{"name": "Christmas", "amount": "1000"}]);
+            }
+            data
+        })
+        .collect()
+}
+
+async fn calculate_annual(url: &str, data: Value) -> Result<(StatusCode, Value)> {
+    let response = reqwest::Client::new()
+        .post(format!("{url}/v1/calculate/annual"))
+        .json(&data)
+        .send()
+        .await?;
+    Ok((response.status(), response.json().await?))
+}
+
+#[tokio::test]
+async fn annual_returns_every_payslip_and_the_totals() -> Result<()> {
+    let (status, results) =
+        calculate_annual(LOCALHOST, json!({"year": "2024", "months": months(true)})).await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(results["year"], 2024);
+    let months = results["months"].as_array().unwrap();
+    assert_eq!(months.len(), 12);
+    assert_eq!(months[0]["month"], 1);
+    assert_eq!(months[0]["payslip"]["netIncome"], 5850.0);
+    assert_eq!(months[11]["payslip"]["bruteIncome"], 11000.0);
+    assert_eq!(results["totals"]["bruteIncome"], 121000.0);
+    assert_eq!(results["totals"]["netIncome"], 70785.0);
+    assert_eq!(results["totals"]["cas"], 30250.0);
+    assert_eq!(results["totals"]["cam"], 2722.5);
+    assert_eq!(results["labels"]["cas"], "Pension contribution (CAS)");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn annual_reports_the_invalid_months() -> Result<()> {
+    let mut months = months(false);
+    months[3]["income"] = json!("abc");
+    let (status, report) = calculate_annual(LOCALHOST, json!({"months": months})).await?;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "months[3].income");
+
+    let (status, report) = calculate_annual(LOCALHOST, json!({"months": &months[..11]})).await?;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "months");
+    assert_eq!(
+        report["errors"][0]["message"],
+        "Invalid number of months 11, expected the 12 months of the year."
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn annual_rejects_a_year_set_on_a_month() -> Result<()> {
+    let mut months = months(false);
+    months[5]["year"] = json!("2023");
+    let (status, report) =
+        calculate_annual(LOCALHOST, json!({"year": "2024", "months": months})).await?;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "months[5].year");
+    assert_eq!(
+        report["errors"][0]["message"],
+        "The year is set once for the whole request."
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn annual_applies_the_rates_changing_during_the_year() -> Result<()> {
+    let server = SpawnedServer::start("annual", 8005, &[]).await;
+    Connection::open(scratch_dir("annual").join("tax_rates.db"))?.execute(
+        "INSERT INTO tax_rate_changes (year, from_month, income_tax, social_security, health_insurance, insurance_contribution)
+         VALUES (2024, 7, 0.10, 0.25, 0.20, 0.0225)",
+        [],
+    )?;
+
+    let (status, results) = calculate_annual(
+        &server.url,
+        json!({"year": "2024", "months": months(false)}),
+    )
+    .await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(results["months"][5]["payslip"]["cass"], 1000.0);
+    assert_eq!(results["months"][6]["payslip"]["cass"], 2000.0);
+    assert_eq!(results["totals"]["cass"], 18000.0);
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::SpawnedServer;
+use serde_json::{json, Value};
+
+static ADMIN_TOKEN: &str = "test-admin-token";
+
+#[tokio::test]
+async fn api_keys_are_metered_and_can_be_revoked() -> Result<()> {
+    let server = SpawnedServer::start(
+        "api-keys",
+        8004,
+        &[
+            ("ADMIN_TOKEN", ADMIN_TOKEN),
+            ("RATE_LIMITS", "/v1/calculate=2"),
+        ],
+    )
+    .await;
+    let client = reqwest::Client::new();
+    let admin_url = format!("{}/admin/api-keys", server.url);
+    let calculate = |api_key: Option<String>| {
+        let request = client
+            .post(format!("{}/v1/calculate", server.url))
+            .json(&json!({"income": 5000, "incomeType": "BRUTE", "currency": "RON"}));
+        match api_key {
+            Some(api_key) => request.header("X-API-Key", api_key),
+            None => request,
+        }
+        .send()
+    };
+
+    // The admin routes need the admin token.
+    let response = client.get(&admin_url).send().await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = client
+        .post(&admin_url)
+        .bearer_auth(ADMIN_TOKEN)
+        .json(&json!({"name": " ", "dailyQuota": "0"}))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let report: Value = response.json().await?;
+    assert_eq!(report["errors"].as_array().unwrap().len(), 2);
+
+    let response = client
+        .post(&admin_url)
+        .bearer_auth(ADMIN_TOKEN)
+        .json(&json!({"name": "Job board", "dailyQuota": "3", "requestsPerMinute": "100"}))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let issued: Value = response.json().await?;
+    let key = issued["key"].as_str().unwrap().to_string();
+    let id = issued["apiKey"]["id"].as_i64().unwrap();
+    assert!(key.starts_with(issued["apiKey"]["prefix"].as_str().unwrap()));
+
+    // Anonymous clients get the lower route limit, the partner its daily quota.
+    for _ in 0..2 {
+        assert_eq!(calculate(None).await?.status(), StatusCode::OK);
+    }
+    assert_eq!(
+        calculate(None).await?.status(),
+        StatusCode::TOO_MANY_REQUESTS
+    );
+    // The probes, metrics and docs are not charged to the key.
+    for path in [
+        "/health/live",
+        "/health/ready",
+        "/v1/health",
+        "/metrics",
+        "/docs",
+    ] {
+        let response = client
+            .get(format!("{}{path}", server.url))
+            .header("X-API-Key", &key)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK, "{path}");
+    }
+    for _ in 0..3 {
+        assert_eq!(calculate(Some(key.clone())).await?.status(), StatusCode::OK);
+    }
+    let response = calculate(Some(key.clone())).await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.headers().contains_key("Retry-After"));
+
+    let api_keys: Value = client
+        .get(&admin_url)
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert_eq!(api_keys[0]["name"], "Job board");
+    assert_eq!(api_keys[0]["requestsToday"], 3);
+    assert!(api_keys[0].get("key").is_none());
+
+    // Unknown and revoked keys are rejected, except by the probes.
+    let response = calculate(Some(String::from("cvk_unknown"))).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = client
+        .get(format!("{}/health/ready", server.url))
+        .header("X-API-Key", "cvk_unknown")
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = client
+        .delete(format!("{admin_url}/{id}"))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let response = calculate(Some(key)).await?;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = client
+        .delete(format!("{admin_url}/{id}"))
+        .bearer_auth(ADMIN_TOKEN)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    Ok(())
+}
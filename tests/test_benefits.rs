@@ -0,0 +1,129 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::json;
+
+#[tokio::test]
+async fn calculate_brute_salary_with_meal_vouchers_happy_path() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "10000",
+        "incomeType": "brute",
+        "currency": "ron",
+        "benefits": [
+            {"type": "meal_voucher", "amount": "40", "count": "20"},
+        ],
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+    let status = response.status();
+    let response: serde_json::Value = response.json().await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["brute_income"], 10000.0);
+    assert_eq!(response["cas"], 2500.0);
+    assert_eq!(response["cass"], 1080.0);
+    assert_eq!(response["income_tax"], 722.0);
+    assert_eq!(response["cam"], 225.0);
+    assert_eq!(response["net_income"], 5698.0);
+    assert_eq!(response["benefits_value"], 800.0);
+    assert_eq!(response["take_home"], 6498.0);
+    assert_eq!(response["total_salary"], 11025.0);
+
+    let benefit = &response["benefits"][0];
+    assert_eq!(benefit["benefit_type"], "MEAL_VOUCHER");
+    assert_eq!(benefit["value"], 800.0);
+    assert_eq!(benefit["cas"], 0.0);
+    assert_eq!(benefit["cass"], 80.0);
+    assert_eq!(benefit["income_tax"], 72.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn benefit_rules_exist_for_every_year_with_tax_rates() -> Result<()> {
+    let client = reqwest::Client::new();
+    for year in ["2023", "2024", "2025", "2026"] {
+        let data = json!({
+            "income": "10000",
+            "incomeType": "BRUTE",
+            "currency": "RON",
+            "year": year,
+            "benefits": [
+                {"type": "meal_voucher", "amount": "40", "count": "20"},
+            ],
+        });
+        let response = client
+            .post(format!("{LOCALHOST}/v1/calculate"))
+            .json(&data)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK, "{year}");
+        let response: serde_json::Value = response.json().await?;
+        assert_eq!(response["cass"], 1080.0, "{year}");
+        assert_eq!(response["incomeTax"], 722.0, "{year}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_net_salary_with_meal_vouchers_keeps_net_income() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "5698",
+        "incomeType": "net",
+        "currency": "ron",
+        "benefits": [
+            {"type": "meal_voucher", "amount": "40", "count": "20"},
+        ],
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+    let status = response.status();
+    let response: serde_json::Value = response.json().await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["brute_income"], 10000.0);
+    assert_eq!(response["net_income"], 5698.0);
+    assert_eq!(response["take_home"], 6498.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_with_wrong_benefit_type_should_respond_error_422() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "10000",
+        "incomeType": "brute",
+        "currency": "ron",
+        "benefits": [
+            {"type": "company_car", "amount": "500", "count": "1"},
+        ],
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let report: serde_json::Value = response.json().await?;
+    assert_eq!(report["errors"][0]["field"], "benefits[0].type");
+    assert_eq!(report["errors"][0]["code"], "unsupported_benefit_type");
+    assert_eq!(
+        report["errors"][0]["message"],
+        "Unsupported benefit type \"company_car\"."
+    );
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 use axum::http::StatusCode;
 use common::LOCALHOST;
 use serde::Deserialize;
-use serde_json::json;
+use serde_json::{json, Value};
 mod common;
 
 #[tokio::test]
@@ -19,7 +19,7 @@ async fn check_health() -> Result<()> {
 }
 
 #[tokio::test]
-async fn calculate_with_wrong_currency_should_respond_error_400() {
+async fn calculate_with_wrong_currency_should_respond_error_422() {
     let client = reqwest::Client::new();
     let data = json!({
         "income": "1000",
@@ -35,13 +35,18 @@ async fn calculate_with_wrong_currency_should_respond_error_400() {
         .await
         .expect("Failed to send request.");
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST.as_u16());
-    let text = response.text().await.expect("Failed to read response body");
-    assert!(text.contains("Currency \"YEN\" not supported"));
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY.as_u16());
+    let report: serde_json::Value = response.json().await.expect("Failed to read response body");
+    assert_eq!(report["errors"][0]["field"], "currency");
+    assert_eq!(report["errors"][0]["code"], "unsupported_currency");
+    assert_eq!(
+        report["errors"][0]["message"],
+        "Currency \"YEN\" not supported."
+    );
 }
 
 #[tokio::test]
-async fn calculate_with_wrong_income_type_should_respond_error_400() {
+async fn calculate_with_wrong_income_type_should_respond_error_422() {
     let client = reqwest::Client::new();
     let data = json!({
         "income": "1000",
@@ -57,13 +62,18 @@ async fn calculate_with_wrong_income_type_should_respond_error_400() {
         .await
         .expect("Failed to send request.");
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST.as_u16());
-    let text = response.text().await.expect("Failed to read response body");
-    assert!(text.contains("Unsupported income type \"wrong-income-type\""));
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY.as_u16());
+    let report: serde_json::Value = response.json().await.expect("Failed to read response body");
+    assert_eq!(report["errors"][0]["field"], "incomeType");
+    assert_eq!(report["errors"][0]["code"], "unsupported_income_type");
+    assert_eq!(
+        report["errors"][0]["message"],
+        "Unsupported income type \"wrong-income-type\"."
+    );
 }
 
 #[tokio::test]
-async fn calculate_with_empty_currency_should_respond_error_400() {
+async fn calculate_with_empty_currency_should_respond_error_422() {
     let client = reqwest::Client::new();
     let data = json!({
         "income": "1000",
@@ -79,13 +89,17 @@ async fn calculate_with_empty_currency_should_respond_error_400() {
         .await
         .expect("Failed to send request.");
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST.as_u16());
-    let text = response.text().await.expect("Failed to read response body");
-    assert!(text.contains("Currency \"\" not supported"));
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY.as_u16());
+    let report: serde_json::Value = response.json().await.expect("Failed to read response body");
+    assert_eq!(report["errors"][0]["field"], "currency");
+    assert_eq!(
+        report["errors"][0]["message"],
+        "Currency \"\" not supported."
+    );
 }
 
 #[tokio::test]
-async fn calculate_missing_salary_should_respond_400() {
+async fn calculate_missing_salary_should_respond_422_with_every_error() {
     let client = reqwest::Client::new();
     let data = json!({
         // Missing "salary"
@@ -100,9 +114,14 @@ async fn calculate_missing_salary_should_respond_400() {
         .await
         .expect("Failed to send request");
 
-    assert_eq!(response.status(), StatusCode::BAD_REQUEST.as_u16());
-    let text = response.text().await.unwrap();
-    assert!(text.contains("Invalid or missing income."));
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY.as_u16());
+    let report: serde_json::Value = response.json().await.unwrap();
+    let errors = report["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0]["field"], "income");
+    assert_eq!(errors[0]["code"], "missing");
+    assert_eq!(errors[0]["message"], "Invalid or missing income.");
+    assert_eq!(errors[1]["field"], "incomeType");
 }
 
 #[derive(Debug, Deserialize)]
@@ -211,3 +230,183 @@ async fn calculate_brute_salary_works_for_year_2023() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn calculate_total_cost_solves_the_brute_salary() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "10225",
+        "incomeType": "total_cost",
+        "currency": "ron",
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/v1/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+    let status = response.status();
+    let response: Value = response.json().await?;
+
+    assert_eq!(status, StatusCode::OK.as_u16());
+    assert_eq!(response["bruteIncome"], 10000.0);
+    assert_eq!(response["netIncome"], 5850.0);
+    assert_eq!(response["cam"], 225.0);
+    assert_eq!(response["totalSalary"], 10225.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_total_cost_covers_overtime_and_benefits() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "15000",
+        "incomeType": "total_cost",
+        "currency": "ron",
+        "overtime": [{"hours": "10"}],
+        "bonuses": [{"amount": "500"}],
+        "benefits": [{"type": "meal_voucher", "amount": "40", "count": "20"}],
+    });
+    let response: Value = client
+        .post(format!("{LOCALHOST}/v1/calculate"))
+        .json(&data)
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    assert_eq!(response["totalSalary"], 15000.0);
+    assert_eq!(response["incomeComponents"].as_array().unwrap().len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_total_cost_lower_than_the_benefits_should_respond_error_422() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "500",
+        "incomeType": "total_cost",
+        "currency": "ron",
+        "benefits": [{"type": "meal_voucher", "amount": "40", "count": "20"}],
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/v1/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+    let status = response.status();
+    let report: Value = response.json().await?;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY.as_u16());
+    assert_eq!(report["errors"][0]["field"], "income");
+    assert_eq!(report["errors"][0]["code"], "total_cost_too_low");
+    assert_eq!(
+        report["errors"][0]["message"],
+        "Invalid income 500, the benefits and payments alone cost the employer more."
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_accepts_decimal_incomes_with_local_separators() -> Result<()> {
+    let client = reqwest::Client::new();
+    for income in [
+        json!("4.500,50"),
+        json!("4,500.50"),
+        json!("4500,50"),
+        json!(4500.5),
+    ] {
+        let data = json!({
+            "income": income,
+            "incomeType": "brute",
+            "currency": "ron",
+        });
+        let response = client
+            .post(format!("{LOCALHOST}/calculate"))
+            .json(&data)
+            .send()
+            .await?;
+        let status = response.status();
+        let response: CalculationResponse = response.json().await?;
+
+        assert_eq!(status, StatusCode::OK.as_u16());
+        assert_eq!(response.brute_income, 4500.5);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_with_malformed_income_should_respond_error_422() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "4.50.0",
+        "incomeType": "brute",
+        "currency": "ron",
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY.as_u16());
+    let report: serde_json::Value = response.json().await?;
+    assert_eq!(report["errors"][0]["field"], "income");
+    assert_eq!(report["errors"][0]["code"], "invalid_amount");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_with_too_large_income_should_respond_error_422() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": 1_000_000_000,
+        "incomeType": "brute",
+        "currency": "ron",
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY.as_u16());
+    let report: serde_json::Value = response.json().await?;
+    assert_eq!(report["errors"][0]["field"], "income");
+    assert_eq!(report["errors"][0]["code"], "amount_too_large");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_with_invalid_year_and_custom_tax_should_respond_error_422() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "10000",
+        "incomeType": "brute",
+        "currency": "ron",
+        "customTax": "abc",
+        "year": "last year",
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY.as_u16());
+    let report: serde_json::Value = response.json().await?;
+    let errors = report["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0]["field"], "customTax");
+    assert_eq!(errors[0]["code"], "invalid_custom_tax");
+    assert_eq!(errors[1]["field"], "year");
+    assert_eq!(errors[1]["code"], "invalid_year");
+    assert_eq!(errors[1]["message"], "Invalid year \"last year\".");
+
+    Ok(())
+}
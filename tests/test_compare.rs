@@ -0,0 +1,55 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::{json, Value};
+
+async fn compare(data: Value) -> Result<(StatusCode, Value)> {
+    let response = reqwest::Client::new()
+        .post(format!("{LOCALHOST}/v1/compare"))
+        .json(&data)
+        .send()
+        .await?;
+    Ok((response.status(), response.json().await?))
+}
+
+#[tokio::test]
+async fn compare_returns_every_regime_for_the_same_budget() -> Result<()> {
+    let (status, results) = compare(json!({"budget": "120000", "year": "2024"})).await?;
+
+    assert_eq!(status, StatusCode::OK);
+    let outcomes = results["outcomes"].as_array().unwrap();
+    let regimes: Vec<&str> = outcomes
+        .iter()
+        .map(|outcome| outcome["regime"].as_str().unwrap())
+        .collect();
+    assert_eq!(regimes, ["CIM", "PFA", "MICRO"]);
+
+    // The whole budget is the employer cost of the job.
+    let employment = &outcomes[0];
+    let employment_total =
+        employment["netIncome"].as_f64().unwrap() + employment["totalTaxes"].as_f64().unwrap();
+    assert!((employment_total - 120000.0).abs() < 1.0);
+
+    assert_eq!(outcomes[1]["netIncome"], 77220.0);
+    assert_eq!(outcomes[1]["totalTaxes"], 42780.0);
+    assert_eq!(outcomes[1]["effectiveTaxRate"], 35.65);
+    assert_eq!(outcomes[2]["netIncome"], 89062.92);
+    assert_eq!(outcomes[2]["totalTaxes"], 30937.08);
+    assert_eq!(outcomes[2]["effectiveTaxRate"], 25.78);
+    assert_eq!(results["best"], "MICRO");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn compare_requires_a_budget() -> Result<()> {
+    let (status, report) = compare(json!({"year": "abc"})).await?;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "budget");
+    assert_eq!(report["errors"][1]["field"], "year");
+
+    Ok(())
+}
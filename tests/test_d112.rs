@@ -0,0 +1,143 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use common::{scratch_dir, LOCALHOST};
+use serde_json::{json, Value};
+use std::process::Command;
+
+static D112_XSD: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/xsd/d112.xsd");
+
+fn payroll() -> Value {
+    json!({
+        "employer": {"cif": "RO14841555", "name": "Calven & Partners SRL"},
+        "year": "2024",
+        "month": "3",
+        "employees": [
+            {
+                "name": "Ana Popescu",
+                "cnp": "2900101123459",
+                "income": "10000",
+                "incomeType": "BRUTE",
+                "currency": "RON",
+            },
+            {
+                "name": "Ion Ionescu",
+                "cnp": "1850505123457",
+                "income": "5000",
+                "incomeType": "BRUTE",
+                "currency": "RON",
+            },
+        ],
+    })
+}
+
+// Checks the file against the bundled schema with xmllint.
+fn validate_against_xsd(xml: &str) -> Result<()> {
+    let path = scratch_dir("d112").join("d112.xml");
+    std::fs::write(&path, xml)?;
+    let output = Command::new("xmllint")
+        .args(["--noout", "--schema", D112_XSD])
+        .arg(&path)
+        .output()?;
+    assert!(
+        output.status.success(),
+        "The export does not follow the D112 schema: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn d112_export_follows_the_schema() -> Result<()> {
+    let response = reqwest::Client::new()
+        .post(format!("{LOCALHOST}/v1/payroll/d112"))
+        .json(&payroll())
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/xml");
+    assert_eq!(
+        response.headers()[CONTENT_DISPOSITION],
+        "attachment; filename=\"d112-summary-2024-03.xml\""
+    );
+    let xml = response.text().await?;
+    validate_against_xsd(&xml)?;
+
+    assert!(xml.contains("xmlns=\"urn:calven:d112-summary:v1\""));
+    assert!(xml.contains("luna_r=\"3\" an_r=\"2024\" totalPlata_A=\"6563\""));
+    assert!(xml.contains("cif=\"14841555\" den=\"Calven &amp; Partners SRL\" nrAsig=\"2\""));
+    assert!(xml.contains("<angajatorA A_codOblig=\"412\" A_datorat=\"3750\"/>"));
+    assert!(xml.contains("<angajatorA A_codOblig=\"432\" A_datorat=\"1500\"/>"));
+    // The CAM of 112.5 is declared as 113.
+    assert!(xml.contains("<angajatorA A_codOblig=\"480\" A_datorat=\"338\"/>"));
+    assert!(xml.contains("<angajatorA A_codOblig=\"602\" A_datorat=\"975\"/>"));
+    assert!(xml.contains("<asigurat cnpAsig=\"2900101123459\" numeAsig=\"Ana Popescu\">"));
+    assert!(xml.contains(
+        "<asiguratA venitBrut=\"10000\" cas=\"2500\" cass=\"1000\" impozit=\"650\" cam=\"225\" venitNet=\"5850\"/>"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn d112_export_requires_the_employer_month_and_codes() -> Result<()> {
+    let mut data = payroll();
+    data["employer"] = json!({"cif": "RO12AB"});
+    data["month"] = Value::Null;
+    data["employees"][0]["cnp"] = json!("2900101123456");
+    data["employees"][1]["cnp"] = json!("123");
+
+    let response = reqwest::Client::new()
+        .post(format!("{LOCALHOST}/v1/payroll/d112"))
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let report: Value = response.json().await?;
+    let fields: Vec<&str> = report["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|error| error["field"].as_str())
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            "employer.cif",
+            "employer.name",
+            "month",
+            "employees[0].cnp",
+            "employees[1].cnp"
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn d112_export_declares_the_taxable_benefits() -> Result<()> {
+    let mut data = payroll();
+    data["employees"][0]["benefits"] =
+        json!([{"type": "meal_voucher", "amount": "40", "count": "20"}]);
+
+    let response = reqwest::Client::new()
+        .post(format!("{LOCALHOST}/v1/payroll/d112"))
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let xml = response.text().await?;
+    validate_against_xsd(&xml)?;
+    // The meal vouchers owe CASS and income tax, they are declared with the salary.
+    assert!(xml.contains("totalBrut=\"15800\""));
+    assert!(xml.contains(
+        "<asiguratA venitBrut=\"10800\" cas=\"2500\" cass=\"1080\" impozit=\"722\" cam=\"225\" venitNet=\"5698\"/>"
+    ));
+
+    Ok(())
+}
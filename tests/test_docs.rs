@@ -0,0 +1,417 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::StatusCode;
+use common::{scratch_dir, SpawnedServer, LOCALHOST};
+use rusqlite::Connection;
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+
+static ADMIN_TOKEN: &str = "docs-admin-token";
+
+// The server a documented response is provoked on: a working one, one limited to a single
+// request per minute on every limited route, one without the rates of any year, one whose
+// tables were dropped and one whose database cannot be opened.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    Working,
+    RateLimited,
+    Outdated,
+    Broken,
+    Unavailable,
+}
+
+struct Servers {
+    working: SpawnedServer,
+    rate_limited: SpawnedServer,
+    outdated: SpawnedServer,
+    broken: SpawnedServer,
+    unavailable: SpawnedServer,
+}
+
+impl Servers {
+    fn url(&self, target: Target) -> &str {
+        let server = match target {
+            Target::Working => &self.working,
+            Target::RateLimited => &self.rate_limited,
+            Target::Outdated => &self.outdated,
+            Target::Broken => &self.broken,
+            Target::Unavailable => &self.unavailable,
+        };
+        &server.url
+    }
+}
+
+#[derive(Debug)]
+struct Case {
+    target: Target,
+    path: String,
+    body: Option<Value>,
+    admin: bool,
+}
+
+impl Case {
+    fn on(target: Target, path: &str, body: Option<Value>) -> Case {
+        Case {
+            target,
+            path: path.to_string(),
+            body,
+            admin: true,
+        }
+    }
+}
+
+// Identifiers created before the cases run, they are substituted in the path templates.
+struct Fixtures {
+    shared_calculation_id: String,
+    api_key_id: i64,
+}
+
+fn salary(year: &str) -> Value {
+    json!({"income": "10000", "incomeType": "BRUTE", "currency": "RON", "year": year})
+}
+
+fn payroll(year: &str) -> Value {
+    json!({
+        "employer": {"cif": "RO14841555", "name": "Calven SRL"},
+        "year": year,
+        "month": "3",
+        "employees": [{
+            "name": "Ana Popescu",
+            "cnp": "2900101123459",
+            "income": "10000",
+            "incomeType": "BRUTE",
+            "currency": "RON",
+        }],
+    })
+}
+
+// A request body accepted by a POST route, for the given year.
+fn valid_body(path: &str, year: &str) -> Value {
+    match path {
+        "/calculate" | "/calculations" => salary(year),
+        "/calculate/annual" => {
+            let month = json!({"income": "10000", "incomeType": "BRUTE", "currency": "RON"});
+            json!({"year": year, "months": vec![month; 12]})
+        }
+        "/compare" => json!({"budget": "120000", "year": year}),
+        "/micro-enterprise" => json!({"revenue": "200000", "year": year}),
+        "/other-income" => json!({
+            "incomes": [{"category": "RENTAL", "amount": "60000"}],
+            "year": year,
+        }),
+        "/payroll" | "/payroll/d112" => payroll(year),
+        "/real-income" => json!({"from": salary(year), "to": salary("2024")}),
+        path => panic!("No request body for POST {path}"),
+    }
+}
+
+// The request provoking a documented response, the legacy aliases share the cases of their
+// `/v1` successor. A documented response without a case fails the test.
+fn case(path: &str, method: &str, status: &str, fixtures: &Fixtures) -> Case {
+    let route = path.strip_prefix("/v1").unwrap_or(path);
+    let with_id = |id: &str| path.replace("{id}", id);
+
+    match (method, route, status) {
+        ("get", _, "200") if !route.contains('{') && route != "/admin/api-keys" => {
+            Case::on(Target::Working, path, None)
+        }
+        ("get", "/health/ready", "503") => Case::on(Target::Broken, path, None),
+        ("get", "/taxes", "404") => Case::on(Target::Outdated, path, None),
+        ("get", "/taxes", "500") => Case::on(Target::Broken, path, None),
+        ("get", "/taxes", "503") => Case::on(Target::Unavailable, path, None),
+
+        ("post", "/admin/api-keys", "201") => {
+            Case::on(Target::Working, path, Some(json!({"name": "Docs"})))
+        }
+        ("post", "/admin/api-keys", "422") => {
+            Case::on(Target::Working, path, Some(json!({"name": " "})))
+        }
+        ("post", "/admin/api-keys", "500") => {
+            Case::on(Target::Broken, path, Some(json!({"name": "Docs"})))
+        }
+        ("get", "/admin/api-keys", "200") => Case::on(Target::Working, path, None),
+        ("get", "/admin/api-keys", "500") => Case::on(Target::Broken, path, None),
+        ("delete", "/admin/api-keys/{id}", "204") => Case::on(
+            Target::Working,
+            &with_id(&fixtures.api_key_id.to_string()),
+            None,
+        ),
+        ("delete", "/admin/api-keys/{id}", "404") => {
+            Case::on(Target::Working, &with_id("999999"), None)
+        }
+        ("delete", "/admin/api-keys/{id}", "500") => Case::on(Target::Broken, &with_id("1"), None),
+        (_, _, "401") if route.starts_with("/admin/") => Case {
+            admin: false,
+            ..Case::on(
+                Target::Working,
+                &with_id("1"),
+                Some(json!({"name": "Docs"})),
+            )
+        },
+
+        ("get", "/calculations/{id}", "200") => Case::on(
+            Target::Working,
+            &with_id(&fixtures.shared_calculation_id),
+            None,
+        ),
+        ("get", "/calculations/{id}", "404") => {
+            Case::on(Target::Working, &with_id("unknown"), None)
+        }
+        ("get", "/calculations/{id}", "500") => Case::on(Target::Broken, &with_id("unknown"), None),
+
+        ("post", _, "200" | "201") => {
+            Case::on(Target::Working, path, Some(valid_body(route, "2024")))
+        }
+        ("post", _, "404") => Case::on(Target::Working, path, Some(valid_body(route, "2019"))),
+        ("post", _, "422") => Case::on(Target::Working, path, Some(json!({}))),
+        ("post", _, "429") => Case::on(Target::RateLimited, path, Some(valid_body(route, "2024"))),
+        ("post", _, "500") => Case::on(Target::Broken, path, Some(valid_body(route, "2024"))),
+
+        _ => panic!("No case provokes the documented {status} of {method} {path}"),
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    for character in name.chars() {
+        if character.is_ascii_uppercase() {
+            snake_case.push('_');
+        }
+        snake_case.push(character.to_ascii_lowercase());
+    }
+    snake_case
+}
+
+// Checks the properties of a JSON object against a documented schema, optional properties
+// may be left out.
+fn assert_matches_schema(spec: &Value, schema: &Value, value: &Value, legacy: bool, at: &str) {
+    if let Some(items) = schema.get("items") {
+        let values = value
+            .as_array()
+            .unwrap_or_else(|| panic!("{at} should be an array"));
+        assert!(!values.is_empty(), "{at} should not be empty");
+        for value in values {
+            assert_matches_schema(spec, items, value, legacy, at);
+        }
+        return;
+    }
+    let Some(name) = schema["$ref"]
+        .as_str()
+        .and_then(|reference| reference.rsplit('/').next())
+    else {
+        return;
+    };
+    let schema = &spec["components"]["schemas"][name];
+    let Some(properties) = schema["properties"].as_object() else {
+        return;
+    };
+    let documented_name = |name: &str| {
+        if legacy {
+            to_snake_case(name)
+        } else {
+            name.to_string()
+        }
+    };
+    let documented: BTreeSet<String> = properties.keys().map(|key| documented_name(key)).collect();
+    let required: BTreeSet<String> = schema["required"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|key| key.as_str())
+        .map(documented_name)
+        .collect();
+    let served: BTreeSet<String> = value
+        .as_object()
+        .unwrap_or_else(|| panic!("{at} should be an object"))
+        .keys()
+        .cloned()
+        .collect();
+
+    assert!(
+        served.is_subset(&documented),
+        "{at} serves undocumented properties {:?}",
+        served.difference(&documented).collect::<Vec<_>>()
+    );
+    assert!(
+        required.is_subset(&served),
+        "{at} leaves out required properties {:?}",
+        required.difference(&served).collect::<Vec<_>>()
+    );
+}
+
+async fn start_servers() -> Result<Servers> {
+    let working = SpawnedServer::start(
+        "docs-working",
+        8006,
+        &[("ADMIN_TOKEN", ADMIN_TOKEN), ("RATE_LIMITS", "none")],
+    )
+    .await;
+
+    let spec: Value = reqwest::get(format!("{}/openapi.json", working.url))
+        .await?
+        .json()
+        .await?;
+    let limited_routes: Vec<String> = spec["paths"]
+        .as_object()
+        .unwrap()
+        .iter()
+        .filter(|(_, operations)| {
+            operations
+                .as_object()
+                .unwrap()
+                .values()
+                .any(|operation| operation["responses"].get("429").is_some())
+        })
+        .map(|(path, _)| format!("{path}=1"))
+        .collect();
+    let rate_limited = SpawnedServer::start(
+        "docs-rate-limited",
+        8007,
+        &[("RATE_LIMITS", &limited_routes.join(","))],
+    )
+    .await;
+
+    let broken = SpawnedServer::start(
+        "docs-broken",
+        8008,
+        &[("ADMIN_TOKEN", ADMIN_TOKEN), ("RATE_LIMITS", "none")],
+    )
+    .await;
+    let conn = Connection::open(scratch_dir("docs-broken").join("tax_rates.db"))?;
+    let tables: Vec<String> = conn
+        .prepare(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+        )?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    for table in tables {
+        conn.execute(&format!("DROP TABLE {table}"), [])?;
+    }
+
+    let outdated = SpawnedServer::start("docs-outdated", 8010, &[]).await;
+    Connection::open(scratch_dir("docs-outdated").join("tax_rates.db"))?
+        .execute("DELETE FROM tax_rates", [])?;
+
+    // A directory in place of the database cannot be opened.
+    let unavailable = SpawnedServer::start("docs-unavailable", 8011, &[]).await;
+    let database = scratch_dir("docs-unavailable").join("tax_rates.db");
+    std::fs::remove_file(&database)?;
+    std::fs::create_dir(&database)?;
+
+    Ok(Servers {
+        working,
+        rate_limited,
+        outdated,
+        broken,
+        unavailable,
+    })
+}
+
+async fn create_fixtures(url: &str) -> Result<Fixtures> {
+    let client = reqwest::Client::new();
+    let shared: Value = client
+        .post(format!("{url}/v1/calculations"))
+        .json(&salary("2024"))
+        .send()
+        .await?
+        .json()
+        .await?;
+    let issued: Value = client
+        .post(format!("{url}/admin/api-keys"))
+        .bearer_auth(ADMIN_TOKEN)
+        .json(&json!({"name": "Revoked by the docs test"}))
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    Ok(Fixtures {
+        shared_calculation_id: shared["id"].as_str().unwrap().to_string(),
+        api_key_id: issued["apiKey"]["id"].as_i64().unwrap(),
+    })
+}
+
+#[tokio::test]
+async fn documented_responses_are_served() -> Result<()> {
+    let servers = start_servers().await?;
+    let fixtures = create_fixtures(servers.url(Target::Working)).await?;
+    let spec: Value = reqwest::get(format!("{}/openapi.json", servers.url(Target::Working)))
+        .await?
+        .json()
+        .await?;
+    let client = reqwest::Client::new();
+
+    for (path, operations) in spec["paths"].as_object().unwrap() {
+        let legacy = !path.starts_with("/v1/")
+            && !path.starts_with("/health/")
+            && !path.starts_with("/admin/")
+            && path != "/metrics";
+        for (method, operation) in operations.as_object().unwrap() {
+            for (status, response) in operation["responses"].as_object().unwrap() {
+                let at = format!("{} {path} {status}", method.to_uppercase());
+                let case = case(path, method, status, &fixtures);
+                let url = servers.url(case.target);
+                let send = || {
+                    let url = format!("{url}{}", case.path);
+                    let request = match method.as_str() {
+                        "get" => client.get(url),
+                        "post" => client.post(url),
+                        "delete" => client.delete(url),
+                        method => panic!("Unexpected method {method} for {path}"),
+                    };
+                    let request = match &case.body {
+                        Some(body) => request.json(body),
+                        None => request,
+                    };
+                    if case.admin {
+                        request.bearer_auth(ADMIN_TOKEN).send()
+                    } else {
+                        request.send()
+                    }
+                };
+
+                if case.target == Target::RateLimited {
+                    send().await?;
+                }
+                let served = send().await?;
+                assert_eq!(served.status().as_str(), status, "{at}");
+
+                let content = response["content"].as_object();
+                let Some((content_type, media_type)) =
+                    content.and_then(|content| content.iter().next())
+                else {
+                    assert!(served.bytes().await?.is_empty(), "{at} should have no body");
+                    continue;
+                };
+                let served_content_type = served
+                    .headers()
+                    .get(CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                assert!(
+                    served_content_type.starts_with(content_type.as_str()),
+                    "{at} is served as {served_content_type} instead of {content_type}"
+                );
+                if content_type == "application/json" {
+                    let value: Value = served.json().await?;
+                    assert_matches_schema(&spec, &media_type["schema"], &value, legacy, &at);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn docs_page_is_served() -> Result<()> {
+    let response = reqwest::get(format!("{LOCALHOST}/docs")).await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.text().await?.contains("/openapi.json"));
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::{json, Value};
+
+async fn calculate(data: Value) -> Result<(StatusCode, Value)> {
+    let response = reqwest::Client::new()
+        .post(format!("{LOCALHOST}/v1/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+    Ok((response.status(), response.json().await?))
+}
+
+#[tokio::test]
+async fn employees_with_a_disability_pay_no_income_tax() -> Result<()> {
+    let (status, results) = calculate(json!({
+        "income": "10000",
+        "incomeType": "BRUTE",
+        "currency": "RON",
+        "year": "2024",
+        "disability": true,
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(results["incomeTax"], 0.0);
+    assert_eq!(results["netIncome"], 6500.0);
+    assert_eq!(results["exemption"], "DISABILITY");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn employees_under_26_are_exempt_up_to_the_income_limit() -> Result<()> {
+    let (status, results) = calculate(json!({
+        "income": "5000",
+        "incomeType": "BRUTE",
+        "currency": "RON",
+        "year": "2024",
+        "birthDate": "2001-05-20",
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(results["incomeTax"], 0.0);
+    assert_eq!(results["netIncome"], 3250.0);
+    assert_eq!(results["exemption"], "UNDER_26");
+
+    // The net income is reached with the exempt rates.
+    let (_, results) = calculate(json!({
+        "income": "3250",
+        "incomeType": "NET",
+        "currency": "RON",
+        "year": "2024",
+        "birthDate": "2001-05-20",
+    }))
+    .await?;
+    assert_eq!(results["bruteIncome"], 5000.0);
+    assert_eq!(results["exemption"], "UNDER_26");
+
+    // Above the limit the income is taxed as usual.
+    let (_, results) = calculate(json!({
+        "income": "10000",
+        "incomeType": "BRUTE",
+        "currency": "RON",
+        "year": "2024",
+        "birthDate": "2001-05-20",
+    }))
+    .await?;
+    assert_eq!(results["incomeTax"], 650.0);
+    assert_eq!(results["exemption"], Value::Null);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn employees_over_25_are_taxed() -> Result<()> {
+    let (_, results) = calculate(json!({
+        "income": "5000",
+        "incomeType": "BRUTE",
+        "currency": "RON",
+        "year": "2024",
+        "birthDate": "1990-01-01",
+    }))
+    .await?;
+
+    assert_eq!(results["incomeTax"], 325.0);
+    assert_eq!(results["exemption"], Value::Null);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exemptions_exist_for_every_year_with_tax_rates() -> Result<()> {
+    for year in ["2023", "2024", "2025", "2026"] {
+        let (status, results) = calculate(json!({
+            "income": "5000",
+            "incomeType": "BRUTE",
+            "currency": "RON",
+            "year": year,
+            "birthDate": "2003-01-01",
+        }))
+        .await?;
+
+        assert_eq!(status, StatusCode::OK, "{year}");
+        assert_eq!(results["incomeTax"], 0.0, "{year}");
+        assert_eq!(results["exemption"], "UNDER_26", "{year}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_birth_date() -> Result<()> {
+    let (status, report) = calculate(json!({
+        "income": "5000",
+        "incomeType": "BRUTE",
+        "currency": "RON",
+        "birthDate": "20-05-2001",
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "birthDate");
+    assert_eq!(report["errors"][0]["code"], "invalid_birth_date");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exemptions_are_rejected_for_the_self_employed() -> Result<()> {
+    let (status, report) = calculate(json!({
+        "income": "10000",
+        "incomeType": "PFA",
+        "currency": "RON",
+        "birthDate": "2005-01-01",
+        "disability": true,
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "birthDate");
+    assert_eq!(report["errors"][0]["code"], "unsupported_for_income_type");
+    assert_eq!(report["errors"][1]["field"], "disability");
+    assert_eq!(
+        report["errors"][1]["message"],
+        "The disability field is not supported for the PFA income type."
+    );
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+
+#[tokio::test]
+async fn liveness_probe_reports_up() -> Result<()> {
+    let response = reqwest::get(format!("{LOCALHOST}/health/live")).await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let report: serde_json::Value = response.json().await?;
+    assert_eq!(report["status"], "up");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn readiness_probe_reports_every_component() -> Result<()> {
+    let response = reqwest::get(format!("{LOCALHOST}/health/ready")).await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let report: serde_json::Value = response.json().await?;
+    assert_eq!(report["ready"], true);
+    assert_eq!(report["database"]["status"], "up");
+    assert_eq!(report["schema"]["status"], "up");
+    assert_eq!(report["taxRates"]["status"], "up");
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::json;
+
+#[tokio::test]
+async fn calculate_errors_are_localized_from_accept_language() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "incomeType": "brute",
+        "currency": "YEN",
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .header("Accept-Language", "en;q=0.5, ro-RO;q=0.9")
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let report: serde_json::Value = response.json().await?;
+    assert_eq!(
+        report["errors"][0]["message"],
+        "Valoare lipsă sau invalidă pentru venit."
+    );
+    assert_eq!(
+        report["errors"][1]["message"],
+        "Moneda \"YEN\" nu este suportată."
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_breakdown_labels_follow_lang_parameter() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "10000",
+        "incomeType": "brute",
+        "currency": "ron",
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate?lang=ro"))
+        .header("Accept-Language", "en")
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let response: serde_json::Value = response.json().await?;
+    assert_eq!(response["labels"]["income_tax"], "Impozit pe venit");
+    assert_eq!(response["labels"]["cass"], "Contribuția la sănătate (CASS)");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn health_message_is_localized() -> Result<()> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("{LOCALHOST}/health"))
+        .header("Accept-Language", "ro")
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text().await?, "Serverul funcționează corect.");
+
+    Ok(())
+}
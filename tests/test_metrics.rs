@@ -0,0 +1,64 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::json;
+
+#[tokio::test]
+async fn metrics_report_requests_calculations_and_database_queries() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "10000",
+        "incomeType": "brute",
+        "currency": "ron",
+        "year": "2023",
+    });
+    for _ in 0..2 {
+        let response = client
+            .post(format!("{LOCALHOST}/v1/calculate"))
+            .json(&data)
+            .send()
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = client.get(format!("{LOCALHOST}/metrics")).send().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let metrics = response.text().await?;
+
+    assert!(metrics
+        .contains(r#"http_requests_total{method="POST",route="/v1/calculate",status="200"}"#));
+    assert!(metrics.contains(
+        r#"http_request_duration_seconds_bucket{method="POST",route="/v1/calculate",status="200""#
+    ));
+    assert!(
+        metrics.contains(r#"calculations_total{currency="RON",income_type="BRUTE",year="2023"}"#)
+    );
+    assert!(metrics.contains(r#"db_query_duration_seconds_count{query="get_benefit_rules"}"#));
+    assert!(metrics.contains(r#"tax_rates_cache_requests_total{result="hit"}"#));
+    assert!(metrics.contains("tax_rates_cache_hit_ratio "));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_calculations_are_not_counted() -> Result<()> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("{LOCALHOST}/v1/calculate"))
+        .json(&json!({"income": "10000", "incomeType": "BRUTE", "currency": "RON", "year": "2019"}))
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let metrics = client
+        .get(format!("{LOCALHOST}/metrics"))
+        .send()
+        .await?
+        .text()
+        .await?;
+    assert!(!metrics.contains(r#"year="2019""#));
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::{json, Value};
+
+async fn calculate(data: Value) -> Result<(StatusCode, Value)> {
+    let response = reqwest::Client::new()
+        .post(format!("{LOCALHOST}/v1/micro-enterprise"))
+        .json(&data)
+        .send()
+        .await?;
+    Ok((response.status(), response.json().await?))
+}
+
+#[tokio::test]
+async fn micro_enterprise_pays_the_profit_as_dividends() -> Result<()> {
+    let (status, results) =
+        calculate(json!({"revenue": "200000", "expenses": "20000", "year": "2024"})).await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(results["turnoverTax"], 2000.0);
+    // The associate is employed on the minimum wage of 3700.
+    assert_eq!(results["grossSalary"], 44400.0);
+    assert_eq!(results["salaryCosts"], 45399.0);
+    assert_eq!(results["netSalary"], 25974.0);
+    assert_eq!(results["distributableDividend"], 132601.0);
+    assert_eq!(results["dividendTax"], 10608.08);
+    // CASS on 24 minimum wages.
+    assert_eq!(results["cass"], 8880.0);
+    assert_eq!(results["takeHome"], 139086.92);
+    assert_eq!(results["totalTaxes"], 40913.08);
+    assert_eq!(results["effectiveTaxRate"], 22.73);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dividend_tax_follows_the_year() -> Result<()> {
+    for (year, dividend_tax) in [("2024", 0.08), ("2025", 0.10), ("2026", 0.16)] {
+        let (status, results) = calculate(json!({"revenue": "200000", "year": year})).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        let dividend = results["distributableDividend"].as_f64().unwrap();
+        assert_eq!(
+            results["dividendTax"].as_f64().unwrap(),
+            (dividend * dividend_tax * 100.0).round() / 100.0,
+            "{year}"
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn small_dividends_fall_in_a_lower_cass_bracket() -> Result<()> {
+    let (_, results) = calculate(json!({"revenue": "100000", "year": "2024"})).await?;
+
+    // 100000 - 1000 - 45399 leaves 53601 of dividends, between 12 and 24 minimum wages.
+    assert_eq!(results["distributableDividend"], 53601.0);
+    assert_eq!(results["cass"], 4440.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn micro_enterprise_requires_a_revenue() -> Result<()> {
+    let (status, report) = calculate(json!({"expenses": "-5"})).await?;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "revenue");
+    assert_eq!(report["errors"][1]["field"], "expenses");
+
+    Ok(())
+}
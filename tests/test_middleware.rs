@@ -0,0 +1,83 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{SpawnedServer, LOCALHOST};
+
+#[tokio::test]
+async fn responses_are_compressed_when_accepted() -> Result<()> {
+    let client = reqwest::Client::new();
+    for encoding in ["gzip", "br"] {
+        let response = client
+            .get(format!("{LOCALHOST}/openapi.json"))
+            .header("Accept-Encoding", encoding)
+            .send()
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["Content-Encoding"], encoding);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn oversized_bodies_are_rejected() -> Result<()> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("{LOCALHOST}/v1/calculate"))
+        .header("Content-Type", "application/json")
+        .body(format!("{{\"income\": \"{}\"}}", "1".repeat(100 * 1024)))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cors_only_allows_the_configured_origins() -> Result<()> {
+    let server = SpawnedServer::start(
+        "cors",
+        8002,
+        &[(
+            "CORS_ALLOWED_ORIGINS",
+            "https://calven.ro, https://www.calven.ro",
+        )],
+    )
+    .await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .request(
+            reqwest::Method::OPTIONS,
+            format!("{}/v1/calculate", server.url),
+        )
+        .header("Origin", "https://calven.ro")
+        .header("Access-Control-Request-Method", "POST")
+        .header("Access-Control-Request-Headers", "content-type")
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["Access-Control-Allow-Origin"],
+        "https://calven.ro"
+    );
+
+    let response = client
+        .request(
+            reqwest::Method::OPTIONS,
+            format!("{}/v1/calculate", server.url),
+        )
+        .header("Origin", "https://scraper.example")
+        .header("Access-Control-Request-Method", "POST")
+        .send()
+        .await?;
+    assert!(response
+        .headers()
+        .get("Access-Control-Allow-Origin")
+        .is_none());
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{scratch_dir, SpawnedServer, LOCALHOST};
+use rusqlite::Connection;
+use serde_json::{json, Value};
+
+async fn calculate(data: Value) -> Result<(StatusCode, Value)> {
+    let response = reqwest::Client::new()
+        .post(format!("{LOCALHOST}/v1/other-income"))
+        .json(&data)
+        .send()
+        .await?;
+    Ok((response.status(), response.json().await?))
+}
+
+#[tokio::test]
+async fn other_income_declaration_total() -> Result<()> {
+    let (status, results) = calculate(json!({
+        "incomes": [
+            {"category": "RENTAL", "amount": "60000"},
+            {"category": "DIVIDENDS", "amount": "20000"},
+            {"category": "INVESTMENT", "amount": "10000"},
+        ],
+        "year": "2024",
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::OK);
+    // Rents are taxed after the flat deduction of 20%.
+    assert_eq!(results["categories"][0]["taxableIncome"], 48000.0);
+    assert_eq!(results["categories"][0]["incomeTax"], 4800.0);
+    assert_eq!(results["categories"][1]["incomeTax"], 1600.0);
+    assert_eq!(results["categories"][1]["withheldAtSource"], true);
+    assert_eq!(results["categories"][2]["incomeTax"], 1000.0);
+    // 78000 is between 12 and 24 minimum wages of 3700.
+    assert_eq!(results["taxableIncome"], 78000.0);
+    assert_eq!(results["cassBase"], 44400.0);
+    assert_eq!(results["cass"], 4440.0);
+    assert_eq!(results["incomeTax"], 7400.0);
+    assert_eq!(results["withheldTax"], 1600.0);
+    assert_eq!(results["declarationTotal"], 10240.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn withheld_dividend_tax_follows_the_year() -> Result<()> {
+    for (year, income_tax) in [("2024", 1600.0), ("2025", 2000.0), ("2026", 3200.0)] {
+        let (status, results) = calculate(json!({
+            "incomes": [{"category": "DIVIDENDS", "amount": "20000"}],
+            "year": year,
+        }))
+        .await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(results["categories"][0]["incomeTax"], income_tax, "{year}");
+        assert_eq!(results["withheldTax"], income_tax, "{year}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn other_income_under_6_minimum_wages_owes_no_cass() -> Result<()> {
+    let (_, results) = calculate(json!({
+        "incomes": [
+            {"category": "RENTAL", "amount": "12000"},
+            {"category": "rental", "amount": "8000"},
+        ],
+        "year": "2024",
+    }))
+    .await?;
+
+    // Incomes of the same category are declared together.
+    assert_eq!(results["categories"].as_array().unwrap().len(), 1);
+    assert_eq!(results["categories"][0]["amount"], 20000.0);
+    assert_eq!(results["cass"], 0.0);
+    assert_eq!(results["declarationTotal"], 1600.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn other_income_is_capped_at_24_minimum_wages() -> Result<()> {
+    let (_, results) = calculate(json!({
+        "incomes": [{"category": "INVESTMENT", "amount": "500000"}],
+        "year": "2024",
+    }))
+    .await?;
+
+    assert_eq!(results["cassBase"], 88800.0);
+    assert_eq!(results["cass"], 8880.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_other_income() -> Result<()> {
+    let (status, report) = calculate(json!({"incomes": []})).await?;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "incomes");
+
+    let (status, report) = calculate(json!({
+        "incomes": [{"category": "SALARY", "amount": "-5"}],
+    }))
+    .await?;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "incomes[0].category");
+    assert_eq!(report["errors"][0]["code"], "unsupported_income_category");
+    assert_eq!(report["errors"][1]["field"], "incomes[0].amount");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn missing_category_rate_names_the_category() -> Result<()> {
+    // The server seeds the database, the rate is removed before starting it again.
+    drop(SpawnedServer::start("category-rate", 8015, &[]).await);
+    Connection::open(scratch_dir("category-rate").join("tax_rates.db"))?.execute(
+        "DELETE FROM other_income_rates WHERE year = 2024 AND category = 'RENTAL'",
+        [],
+    )?;
+    let server = SpawnedServer::start("category-rate", 8015, &[]).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/v1/other-income", server.url))
+        .json(&json!({"year": "2024", "incomes": [{"category": "RENTAL", "amount": "1000"}]}))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response.text().await?,
+        "Tax rate for the RENTAL income category not found for the year 2024."
+    );
+
+    Ok(())
+}
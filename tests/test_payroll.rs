@@ -0,0 +1,139 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::{json, Value};
+
+async fn payroll(data: Value) -> Result<(StatusCode, Value)> {
+    let response = reqwest::Client::new()
+        .post(format!("{LOCALHOST}/v1/payroll"))
+        .json(&data)
+        .send()
+        .await?;
+    Ok((response.status(), response.json().await?))
+}
+
+#[tokio::test]
+async fn payroll_totals_the_employer_contributions() -> Result<()> {
+    let (status, results) = payroll(json!({
+        "year": "2024",
+        "month": "3",
+        "employees": [
+            {
+                "name": "Ana Popescu",
+                "sector": "IT",
+                "deductions": "500",
+                "income": 10000,
+                "incomeType": "BRUTE",
+                "currency": "RON",
+            },
+            {
+                "name": "Ion Ionescu",
+                "sector": "Construction",
+                "income": "5000",
+                "incomeType": "BRUTE",
+                "currency": "RON",
+            },
+        ],
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(results["month"], 3);
+    assert_eq!(results["employees"][0]["payslip"]["netIncome"], 5850.0);
+    assert_eq!(results["employees"][0]["paidNet"], 5350.0);
+    assert_eq!(results["employees"][1]["payslip"]["incomeTax"], 325.0);
+
+    let totals = &results["totals"];
+    assert_eq!(totals["employees"], 2);
+    assert_eq!(totals["bruteIncome"], 15000.0);
+    assert_eq!(totals["cas"], 3750.0);
+    assert_eq!(totals["cass"], 1500.0);
+    assert_eq!(totals["incomeTax"], 975.0);
+    assert_eq!(totals["cam"], 337.5);
+    assert_eq!(totals["contributions"], 6562.5);
+    assert_eq!(totals["deductions"], 500.0);
+    assert_eq!(totals["paidNet"], 8275.0);
+    assert_eq!(totals["totalSalary"], 15337.5);
+
+    assert_eq!(results["sectors"]["IT"]["bruteIncome"], 10000.0);
+    assert_eq!(results["sectors"]["Construction"]["employees"], 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn payroll_errors_point_to_the_employee() -> Result<()> {
+    let (status, report) = payroll(json!({"employees": []})).await?;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "employees");
+
+    let (status, report) = payroll(json!({
+        "month": "13",
+        "employees": [
+            {"name": "Ana Popescu", "income": "5000", "incomeType": "BRUTE", "currency": "RON"},
+            {"income": "-5", "incomeType": "BRUTE", "currency": "RON"},
+        ],
+    }))
+    .await?;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "month");
+    assert_eq!(report["errors"][1]["field"], "employees[1].name");
+    assert_eq!(report["errors"][2]["field"], "employees[1].income");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn payroll_rejects_the_period_of_an_employee() -> Result<()> {
+    let (status, report) = payroll(json!({
+        "year": "2024",
+        "month": "3",
+        "employees": [
+            {
+                "name": "Ana Popescu",
+                "year": "2023",
+                "month": "5",
+                "income": "5000",
+                "incomeType": "BRUTE",
+                "currency": "RON",
+            },
+        ],
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "employees[0].year");
+    assert_eq!(report["errors"][1]["field"], "employees[0].month");
+    assert_eq!(
+        report["errors"][1]["message"],
+        "The month is set once for the whole request."
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deductions_are_capped_at_the_net_income() -> Result<()> {
+    let (status, results) = payroll(json!({
+        "year": "2024",
+        "employees": [
+            {
+                "name": "Ana Popescu",
+                "deductions": "8000",
+                "income": "10000",
+                "incomeType": "BRUTE",
+                "currency": "RON",
+            },
+        ],
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(results["employees"][0]["deductions"], 5850.0);
+    assert_eq!(results["employees"][0]["paidNet"], 0.0);
+    assert_eq!(results["totals"]["paidNet"], 0.0);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::json;
+
+#[tokio::test]
+async fn calculate_payslip_with_bonus_overtime_and_one_off_payment_happy_path() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "8400",
+        "incomeType": "brute",
+        "currency": "ron",
+        "bonuses": [{"name": "Performance", "amount": "1000"}],
+        "overtime": [{"hours": "10"}],
+        "oneOffPayments": [{"name": "Relocation", "amount": "725"}],
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+    let status = response.status();
+    let response: serde_json::Value = response.json().await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["brute_income"], 11000.0);
+    assert_eq!(response["cas"], 2750.0);
+    assert_eq!(response["cass"], 1100.0);
+    assert_eq!(response["income_tax"], 715.0);
+    assert_eq!(response["net_income"], 6435.0);
+    assert_eq!(response["cam"], 247.5);
+
+    let components = response["income_components"].as_array().unwrap();
+    assert_eq!(components.len(), 4);
+    assert_eq!(components[0]["component_type"], "BASE_SALARY");
+    assert_eq!(components[0]["brute_income"], 8400.0);
+    assert_eq!(components[1]["component_type"], "BONUS");
+    assert_eq!(components[1]["name"], "Performance");
+    assert_eq!(components[1]["brute_income"], 1000.0);
+    assert_eq!(components[1]["net_income"], 585.0);
+    // 8400 / 168 hours = 50 per hour, paid at 175% for overtime.
+    assert_eq!(components[2]["component_type"], "OVERTIME");
+    assert_eq!(components[2]["brute_income"], 875.0);
+    assert_eq!(components[3]["component_type"], "ONE_OFF_PAYMENT");
+    assert_eq!(components[3]["brute_income"], 725.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn calculate_with_missing_overtime_hours_should_respond_error_422() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "8400",
+        "incomeType": "brute",
+        "currency": "ron",
+        "overtime": [{"multiplier": "2"}],
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let report: serde_json::Value = response.json().await?;
+    assert_eq!(report["errors"][0]["field"], "overtime[0].hours");
+    assert_eq!(report["errors"][0]["code"], "invalid_hours");
+    assert_eq!(
+        report["errors"][0]["message"],
+        "Invalid or missing overtime hours."
+    );
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{scratch_dir, SpawnedServer, LOCALHOST};
+use rusqlite::Connection;
+use serde_json::{json, Value};
+
+async fn calculate(data: Value) -> Result<(StatusCode, Value)> {
+    let response = reqwest::Client::new()
+        .post(format!("{LOCALHOST}/v1/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+    Ok((response.status(), response.json().await?))
+}
+
+#[tokio::test]
+async fn pfa_above_the_upper_cas_threshold() -> Result<()> {
+    let (status, results) = calculate(json!({
+        "income": "120000",
+        "expenses": "20000",
+        "incomeType": "PFA",
+        "currency": "RON",
+        "year": "2024",
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::OK);
+    // CAS on 24 minimum wages of 3700, CASS and income tax on the net income.
+    assert_eq!(results["cas"], 22200.0);
+    assert_eq!(results["cass"], 10000.0);
+    assert_eq!(results["incomeTax"], 6780.0);
+    assert_eq!(results["netIncome"], 61020.0);
+    assert_eq!(results["expenses"], 20000.0);
+    assert_eq!(results["cam"], 0.0);
+    assert_eq!(results["stateTaxPercentage"], 32.48);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pfa_below_the_cas_threshold_pays_the_minimum_cass() -> Result<()> {
+    let (status, results) = calculate(json!({
+        "income": "15000",
+        "incomeType": "PFA",
+        "currency": "RON",
+        "year": "2024",
+    }))
+    .await?;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(results["cas"], 0.0);
+    // CASS is due on at least 6 minimum wages.
+    assert_eq!(results["cass"], 2220.0);
+    assert_eq!(results["incomeTax"], 1278.0);
+    assert_eq!(results["netIncome"], 11502.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pfa_cass_is_capped_at_60_then_72_minimum_wages() -> Result<()> {
+    let (_, results) = calculate(json!({
+        "income": "500000",
+        "incomeType": "PFA",
+        "currency": "RON",
+        "year": "2024",
+    }))
+    .await?;
+
+    assert_eq!(results["cas"], 22200.0);
+    assert_eq!(results["cass"], 22200.0);
+
+    // The cap went up to 72 minimum wages of 4050 in 2025.
+    let (_, results) = calculate(json!({
+        "income": "500000",
+        "incomeType": "PFA",
+        "currency": "RON",
+        "year": "2025",
+    }))
+    .await?;
+
+    assert_eq!(results["cas"], 24300.0);
+    assert_eq!(results["cass"], 29160.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn corrected_thresholds_reach_older_databases() -> Result<()> {
+    let database = scratch_dir("pfa-upgrade").join("tax_rates.db");
+    let conn = Connection::open(&database)?;
+    conn.execute_batch(
+        "CREATE TABLE pfa_thresholds (
+            year INTEGER PRIMARY KEY,
+            minimum_wage REAL NOT NULL,
+            cas_lower_wages REAL NOT NULL,
+            cas_upper_wages REAL NOT NULL,
+            cass_min_wages REAL NOT NULL,
+            cass_max_wages REAL NOT NULL
+        );
+        INSERT INTO pfa_thresholds VALUES (2025, 4050, 12, 24, 6, 60);
+        PRAGMA user_version = 11;",
+    )?;
+
+    let _server = SpawnedServer::start("pfa-upgrade", 8012, &[]).await;
+
+    let cass_max_wages: f64 = conn.query_row(
+        "SELECT cass_max_wages FROM pfa_thresholds WHERE year = 2025",
+        [],
+        |row| row.get(0),
+    )?;
+    assert_eq!(cass_max_wages, 72.0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn payslip_fields_and_expenses_depend_on_the_income_type() -> Result<()> {
+    let (status, report) = calculate(json!({
+        "income": "120000",
+        "incomeType": "PFA",
+        "currency": "RON",
+        "bonuses": [{"amount": "1000"}],
+    }))
+    .await?;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "bonuses");
+    assert_eq!(report["errors"][0]["code"], "unsupported_for_income_type");
+
+    let (status, report) = calculate(json!({
+        "income": "10000",
+        "incomeType": "BRUTE",
+        "currency": "RON",
+        "expenses": "100",
+    }))
+    .await?;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(report["errors"][0]["field"], "expenses");
+
+    Ok(())
+}
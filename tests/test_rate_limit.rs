@@ -0,0 +1,107 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::SpawnedServer;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn clients_over_the_route_limit_get_429_with_retry_after() -> Result<()> {
+    let server = SpawnedServer::start(
+        "rate-limit",
+        8003,
+        &[
+            ("RATE_LIMITS", "/v1/calculate=3"),
+            ("TRUSTED_PROXY_HEADER", "X-Forwarded-For"),
+        ],
+    )
+    .await;
+    let client = reqwest::Client::new();
+    let calculate = |forwarded_for: &'static str| {
+        client
+            .post(format!("{}/v1/calculate", server.url))
+            .header("X-Forwarded-For", forwarded_for)
+            .json(&json!({"income": 5000, "incomeType": "BRUTE", "currency": "RON"}))
+            .send()
+    };
+
+    for _ in 0..3 {
+        let response = calculate("203.0.113.7").await?;
+        assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    let response = calculate("203.0.113.7").await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = response.headers()["Retry-After"].to_str()?.parse()?;
+    assert!((1..=20).contains(&retry_after));
+
+    // The proxy appends the address it got the request from, a leading entry sent by the
+    // client does not make it a new client.
+    let response = calculate("198.51.100.1, 203.0.113.7").await?;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    // Other clients and routes without a limit are not affected.
+    let response = calculate("203.0.113.8").await?;
+    assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let response = client
+        .get(format!("{}/v1/taxes", server.url))
+        .send()
+        .await?;
+    assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limited_requests_do_not_use_the_daily_quota() -> Result<()> {
+    let server = SpawnedServer::start(
+        "rate-limit-quota",
+        8014,
+        &[
+            ("ADMIN_TOKEN", "test-admin-token"),
+            ("RATE_LIMITS", "/v1/calculate=100"),
+        ],
+    )
+    .await;
+    let client = reqwest::Client::new();
+    let admin_url = format!("{}/admin/api-keys", server.url);
+    let issued: Value = client
+        .post(&admin_url)
+        .bearer_auth("test-admin-token")
+        .json(&json!({"name": "Job board", "dailyQuota": "10", "requestsPerMinute": "2"}))
+        .send()
+        .await?
+        .json()
+        .await?;
+    let key = issued["key"].as_str().unwrap();
+
+    let mut statuses = Vec::new();
+    for _ in 0..3 {
+        let response = client
+            .post(format!("{}/v1/calculate", server.url))
+            .header("X-API-Key", key)
+            .json(&json!({"income": 5000, "incomeType": "BRUTE", "currency": "RON"}))
+            .send()
+            .await?;
+        statuses.push(response.status());
+    }
+    assert_eq!(
+        statuses,
+        [
+            StatusCode::OK,
+            StatusCode::OK,
+            StatusCode::TOO_MANY_REQUESTS
+        ]
+    );
+
+    let api_keys: Value = client
+        .get(&admin_url)
+        .bearer_auth("test-admin-token")
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert_eq!(api_keys[0]["requestsToday"], 2);
+
+    Ok(())
+}
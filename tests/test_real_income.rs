@@ -0,0 +1,119 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::{scratch_dir, SpawnedServer, LOCALHOST};
+use serde_json::{json, Value};
+
+async fn compare(data: Value) -> Result<reqwest::Response> {
+    compare_on(LOCALHOST, data).await
+}
+
+async fn compare_on(url: &str, data: Value) -> Result<reqwest::Response> {
+    Ok(reqwest::Client::new()
+        .post(format!("{url}/v1/real-income"))
+        .json(&data)
+        .send()
+        .await?)
+}
+
+fn salary(income: &str, year: &str) -> Value {
+    json!({"income": income, "incomeType": "BRUTE", "currency": "RON", "year": year})
+}
+
+#[tokio::test]
+async fn salary_restated_in_the_money_of_another_year() -> Result<()> {
+    let response = compare(json!({
+        "from": salary("5000", "2023"),
+        "to": salary("5500", "2024"),
+    }))
+    .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let results: Value = response.json().await?;
+    assert_eq!(results["fromYear"], 2023);
+    assert_eq!(results["toYear"], 2024);
+    assert_eq!(results["inflationFactor"], 1.056);
+    assert_eq!(results["wageFactor"], 1.1391);
+    assert_eq!(results["from"]["netIncome"], 2925.0);
+    assert_eq!(results["restated"]["bruteIncome"], 5280.0);
+    assert_eq!(results["restated"]["netIncome"], 3088.8);
+    assert_eq!(results["to"]["netIncome"], 3217.5);
+    // The 2024 salary buys more than the 2023 one.
+    assert_eq!(results["realNetChange"], 128.7);
+    assert_eq!(results["realNetChangePercentage"], 4.17);
+    assert_eq!(results["wageIndexedNetIncome"], 3331.79);
+
+    Ok(())
+}
+
+// The database committed with the project predates the indices, they are seeded on start.
+#[tokio::test]
+async fn indices_are_seeded_up_to_2025() -> Result<()> {
+    let committed = concat!(env!("CARGO_MANIFEST_DIR"), "/tax_rates.db");
+    std::fs::copy(committed, scratch_dir("indices").join("tax_rates.db"))?;
+    let server = SpawnedServer::start("indices", 8013, &[]).await;
+
+    for url in [LOCALHOST, server.url.as_str()] {
+        let response = compare_on(
+            url,
+            json!({
+                "from": salary("5000", "2023"),
+                "to": salary("5500", "2025"),
+            }),
+        )
+        .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let results: Value = response.json().await?;
+        assert_eq!(results["toYear"], 2025);
+        assert_eq!(results["inflationFactor"], 1.12);
+        assert_eq!(results["wageFactor"], 1.2318);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn real_income_without_indices_for_the_year() -> Result<()> {
+    let response = compare(json!({
+        "from": salary("5000", "2019"),
+        "to": salary("5500", "2024"),
+    }))
+    .await?;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response.text().await?,
+        "Price and wage indices not found for the year 2019."
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn real_income_requires_the_year_of_both_salaries() -> Result<()> {
+    let salary = json!({"income": "5000", "incomeType": "BRUTE", "currency": "RON"});
+    let response = compare(json!({"from": salary, "to": salary})).await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let report: Value = response.json().await?;
+    assert_eq!(report["errors"][0]["field"], "from.year");
+    assert_eq!(report["errors"][0]["code"], "missing");
+    assert_eq!(report["errors"][1]["field"], "to.year");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn real_income_errors_point_to_the_salary() -> Result<()> {
+    let response = compare(json!({"from": salary("-5", "2023")})).await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let report: Value = response.json().await?;
+    assert_eq!(report["errors"][0]["field"], "from.income");
+    assert_eq!(report["errors"][1]["field"], "to");
+    assert_eq!(report["errors"][1]["code"], "missing");
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use anyhow::Result;
+use common::{scratch_dir, SpawnedServer};
+use std::process::Command;
+
+#[tokio::test]
+async fn startup_with_port_in_use_exits_with_bind_error_code() -> Result<()> {
+    // The server under test already listens on port 8000.
+    let output = Command::new(env!("CARGO_BIN_EXE_calven"))
+        .current_dir(scratch_dir("bind"))
+        .env("SERVER_ADDRESS", "0.0.0.0:8000")
+        .output()?;
+
+    assert_eq!(output.status.code(), Some(4));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Could not listen on 0.0.0.0:8000"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sigterm_stops_the_server_gracefully() -> Result<()> {
+    let mut server = SpawnedServer::start("shutdown", 8001, &[]).await;
+
+    Command::new("kill")
+        .args(["-TERM", &server.child.id().to_string()])
+        .status()?;
+    let status = server.child.wait()?;
+
+    assert_eq!(status.code(), Some(0));
+
+    Ok(())
+}
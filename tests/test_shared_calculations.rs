@@ -0,0 +1,119 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn shared_calculations_can_be_fetched_by_id() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({"income": "10000", "incomeType": "BRUTE", "currency": "RON"});
+
+    let response = client
+        .post(format!("{LOCALHOST}/v1/calculations"))
+        .json(&data)
+        .send()
+        .await?;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let location = response.headers()["Location"].to_str()?.to_string();
+    let shared: Value = response.json().await?;
+    let id = shared["id"].as_str().unwrap();
+    assert_eq!(id.len(), 10);
+    assert_eq!(location, format!("/v1/calculations/{id}"));
+    assert_eq!(shared["input"]["income"], "10000");
+    assert_eq!(shared["rules"]["taxRates"]["cas"], 0.25);
+    assert_eq!(shared["results"]["netIncome"], 5850.0);
+    assert!(shared["expiresAt"].as_str() > shared["createdAt"].as_str());
+
+    let calculated: Value = client
+        .post(format!("{LOCALHOST}/v1/calculate"))
+        .json(&data)
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert_eq!(shared["results"], calculated);
+
+    let response = client.get(format!("{LOCALHOST}{location}")).send().await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let fetched: Value = response.json().await?;
+    assert_eq!(fetched, shared);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn shared_calculations_keep_the_rules_applied() -> Result<()> {
+    let client = reqwest::Client::new();
+    let share = |data: Value| {
+        client
+            .post(format!("{LOCALHOST}/v1/calculations"))
+            .json(&data)
+            .send()
+    };
+
+    let shared: Value = share(json!({
+        "income": "10000",
+        "incomeType": "BRUTE",
+        "currency": "RON",
+        "year": "2024",
+        "disability": true,
+        "benefits": [{"type": "meal_voucher", "amount": "40", "count": "20"}],
+    }))
+    .await?
+    .json()
+    .await?;
+    let rules = &shared["rules"];
+    assert_eq!(rules["taxRates"]["year"], 2024);
+    assert!(rules["pfaThresholds"].is_null());
+    assert!(rules["benefitRules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|rule| rule["benefitType"] == "MEAL_VOUCHER"));
+    assert_eq!(rules["exemption"]["exemptionType"], "DISABILITY");
+    assert_eq!(shared["results"]["exemption"], "DISABILITY");
+
+    let shared: Value = share(json!({
+        "income": "120000",
+        "incomeType": "PFA",
+        "currency": "RON",
+        "year": "2024",
+    }))
+    .await?
+    .json()
+    .await?;
+    let rules = &shared["rules"];
+    assert_eq!(rules["pfaThresholds"]["minimumWage"], 3700.0);
+    assert!(rules["exemption"].is_null());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unknown_shared_calculations_are_not_found() -> Result<()> {
+    let response = reqwest::get(format!("{LOCALHOST}/v1/calculations/unknown123")).await?;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response.text().await?,
+        "No calculation with id unknown123, or it expired."
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_calculations_are_not_shared() -> Result<()> {
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!("{LOCALHOST}/v1/calculations"))
+        .json(&json!({"income": "-1", "incomeType": "BRUTE", "currency": "RON"}))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    Ok(())
+}
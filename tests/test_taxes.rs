@@ -2,9 +2,8 @@ mod common;
 
 use anyhow::Result;
 use axum::http::StatusCode;
-use common::LOCALHOST;
-use reqwest;
-use std::ops::Deref;
+use common::{scratch_dir, SpawnedServer, LOCALHOST};
+use rusqlite::Connection;
 
 #[tokio::test]
 async fn fetch_current_taxes_happy_path() -> Result<()> {
@@ -24,3 +23,28 @@ async fn fetch_current_taxes_happy_path() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn missing_rates_and_database_answer_errors() -> Result<()> {
+    let server = SpawnedServer::start("taxes", 8009, &[]).await;
+    let database = scratch_dir("taxes").join("tax_rates.db");
+    let fetch = || reqwest::get(format!("{}/v1/taxes", server.url));
+
+    Connection::open(&database)?.execute("DELETE FROM tax_rates", [])?;
+    let response = fetch().await?;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response.text().await?,
+        "Tax rates not found for the specified year."
+    );
+
+    Connection::open(&database)?.execute("DROP TABLE tax_rates", [])?;
+    assert_eq!(fetch().await?.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    // A directory in place of the database cannot be opened.
+    std::fs::remove_file(&database)?;
+    std::fs::create_dir(&database)?;
+    assert_eq!(fetch().await?.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    Ok(())
+}
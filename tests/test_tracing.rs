@@ -0,0 +1,28 @@
+mod common;
+
+use anyhow::Result;
+use common::LOCALHOST;
+
+#[tokio::test]
+async fn responses_carry_a_generated_request_id() -> Result<()> {
+    let response = reqwest::get(format!("{LOCALHOST}/v1/health")).await?;
+
+    let request_id = response.headers()["x-request-id"].to_str()?;
+    assert_eq!(request_id.len(), 36);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn responses_keep_the_client_request_id() -> Result<()> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("{LOCALHOST}/v1/health"))
+        .header("x-request-id", "client-request-42")
+        .send()
+        .await?;
+
+    assert_eq!(response.headers()["x-request-id"], "client-request-42");
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use anyhow::Result;
+use axum::http::StatusCode;
+use common::LOCALHOST;
+use serde_json::json;
+
+#[tokio::test]
+async fn v1_calculate_responds_with_camel_case() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "10000",
+        "incomeType": "brute",
+        "currency": "ron",
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/v1/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("Deprecation").is_none());
+    let response: serde_json::Value = response.json().await?;
+    assert_eq!(response["bruteIncome"], 10000.0);
+    assert_eq!(response["netIncome"], 5850.0);
+    assert_eq!(response["incomeTax"], 650.0);
+    assert_eq!(
+        response["incomeComponents"][0]["componentType"],
+        "BASE_SALARY"
+    );
+    assert_eq!(response["labels"]["incomeTax"], "Income tax");
+    assert!(response.get("brute_income").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unversioned_calculate_is_a_deprecated_snake_case_alias() -> Result<()> {
+    let client = reqwest::Client::new();
+    let data = json!({
+        "income": "10000",
+        "incomeType": "brute",
+        "currency": "ron",
+    });
+    let response = client
+        .post(format!("{LOCALHOST}/calculate"))
+        .json(&data)
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["Deprecation"], "true");
+    assert_eq!(
+        response.headers()["Link"],
+        "</v1/calculate>; rel=\"successor-version\""
+    );
+    let response: serde_json::Value = response.json().await?;
+    assert_eq!(response["brute_income"], 10000.0);
+    assert_eq!(
+        response["income_components"][0]["component_type"],
+        "BASE_SALARY"
+    );
+    assert_eq!(response["labels"]["income_tax"], "Income tax");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn v1_taxes_uses_the_calculation_names() -> Result<()> {
+    let response = reqwest::get(format!("{LOCALHOST}/v1/taxes")).await?;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let tax_rates: serde_json::Value = response.json().await?;
+    assert_eq!(tax_rates["cas"], 0.25);
+    assert_eq!(tax_rates["cass"], 0.1);
+    assert_eq!(tax_rates["incomeTax"], 0.1);
+    assert_eq!(tax_rates["cam"], 0.0225);
+
+    Ok(())
+}